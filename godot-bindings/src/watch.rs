@@ -12,18 +12,30 @@ use std::time::{Duration, Instant};
 
 use std::io::Write;
 
+/// Environment variable enabling detail metrics (e.g. per-class codegen times), which are off by default to keep the stats file short.
+const DETAIL_ENV_VAR: &str = "GDEXT_CODEGEN_CLASS_TIMING";
+
 pub struct StopWatch {
     last_instant: Instant,
     metrics: Vec<Metric>,
     lwidth: usize,
+    detailed: bool,
 }
 
 impl StopWatch {
     pub fn start() -> Self {
+        println!("cargo:rerun-if-env-changed={DETAIL_ENV_VAR}");
+        let detailed = is_detail_enabled(std::env::var(DETAIL_ENV_VAR).ok().as_deref());
+
+        Self::start_with_details(detailed)
+    }
+
+    fn start_with_details(detailed: bool) -> Self {
         Self {
             last_instant: Instant::now(),
             metrics: vec![],
             lwidth: 0,
+            detailed,
         }
     }
 
@@ -33,8 +45,25 @@ impl StopWatch {
         let name = what.into();
 
         self.last_instant = now;
+        self.push_metric(name, duration, false);
+    }
+
+    /// Records a separately measured duration nested inside the current phase, if `GDEXT_CODEGEN_CLASS_TIMING=1` is set.
+    ///
+    /// Unlike [`record()`][Self::record], this does not end the current phase, and the duration is not added to the total.
+    pub fn record_detail(&mut self, what: impl Into<String>, duration: Duration) {
+        if self.detailed {
+            self.push_metric(what.into(), duration, true);
+        }
+    }
+
+    fn push_metric(&mut self, name: String, duration: Duration, is_detail: bool) {
         self.lwidth = usize::max(self.lwidth, name.len());
-        self.metrics.push(Metric { name, duration });
+        self.metrics.push(Metric {
+            name,
+            duration,
+            is_detail,
+        });
     }
 
     pub fn write_stats_to(self, to_file: &Path) {
@@ -43,13 +72,14 @@ impl StopWatch {
 
         // Accumulate total
         let mut total = Duration::ZERO;
-        for metric in self.metrics.iter() {
+        for metric in self.metrics.iter().filter(|m| !m.is_detail) {
             total += metric.duration;
         }
         let rwidth = log10(total.as_millis());
         let total_metric = Metric {
             name: "total".to_string(),
             duration: total,
+            is_detail: false,
         };
 
         // Write to file
@@ -74,6 +104,10 @@ impl StopWatch {
     }
 }
 
+fn is_detail_enabled(env_value: Option<&str>) -> bool {
+    env_value == Some("1")
+}
+
 fn log10(n: u128) -> usize {
     std::iter::successors(Some(n), |&n| (n >= 10).then_some(n / 10)).count()
 }
@@ -81,4 +115,73 @@ fn log10(n: u128) -> usize {
 struct Metric {
     name: String,
     duration: Duration,
+    is_detail: bool,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_stats(watch: StopWatch, file_suffix: &str) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "gdext-stats-{}-{file_suffix}.txt",
+            std::process::id()
+        ));
+        watch.write_stats_to(&path);
+
+        let stats = std::fs::read_to_string(&path).expect("read stats file");
+        let _ = std::fs::remove_file(&path);
+        stats
+    }
+
+    fn find_millis(stats: &str, phase: &str) -> Option<u128> {
+        stats.lines().find_map(|line| {
+            let rest = line.trim_start().strip_prefix(&format!("{phase}:"))?;
+            rest.trim().strip_suffix(" ms")?.trim().parse().ok()
+        })
+    }
+
+    #[test]
+    fn test_detail_env_value() {
+        assert!(is_detail_enabled(Some("1")));
+        assert!(!is_detail_enabled(Some("0")));
+        assert!(!is_detail_enabled(Some("")));
+        assert!(!is_detail_enabled(None));
+    }
+
+    #[test]
+    fn test_stats_contain_details() {
+        let mut watch = StopWatch::start_with_details(true);
+        watch.record("load_json");
+        watch.record_detail("generate_class_file/Node", Duration::from_millis(300));
+        watch.record_detail("generate_class_file/Node3D", Duration::from_millis(200));
+        watch.record("generate_class_files");
+
+        let stats = read_stats(watch, "details");
+
+        assert_eq!(find_millis(&stats, "generate_class_file/Node"), Some(300));
+        assert_eq!(find_millis(&stats, "generate_class_file/Node3D"), Some(200));
+
+        // Details do not split the enclosing phase, and are not counted twice in the total.
+        let phase = find_millis(&stats, "generate_class_files").expect("phase recorded");
+        let total = find_millis(&stats, "total").expect("total recorded");
+        assert!(phase < 500, "details counted in phase:\n{stats}");
+        assert!(total < 500, "details counted in total:\n{stats}");
+    }
+
+    #[test]
+    fn test_stats_skip_details_by_default() {
+        let mut watch = StopWatch::start_with_details(false);
+        watch.record("load_json");
+        watch.record_detail("generate_class_file/Node", Duration::from_millis(300));
+
+        let stats = read_stats(watch, "no-details");
+
+        assert!(find_millis(&stats, "load_json").is_some());
+        assert!(find_millis(&stats, "total").is_some());
+        assert_eq!(find_millis(&stats, "generate_class_file/Node"), None);
+    }
 }
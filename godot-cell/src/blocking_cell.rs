@@ -122,6 +122,11 @@ impl<T> GdCellBlocking<T> {
         self.inner.as_ref().is_currently_bound()
     }
 
+    /// Similar to [`Self::is_currently_bound`] but only counts mutable references and ignores shared references.
+    pub fn is_currently_mutably_bound(&self) -> bool {
+        self.inner.as_ref().is_currently_mutably_bound()
+    }
+
     /// Blocks the current thread until all mutable and shared references have been dropped.
     fn block_mut<'a>(
         &self,
@@ -67,6 +67,11 @@ impl<T> GdCell<T> {
     pub fn is_currently_bound(&self) -> bool {
         self.0.as_ref().is_currently_bound()
     }
+
+    /// Similar to [`Self::is_currently_bound`] but only counts mutable references and ignores shared references.
+    pub fn is_currently_mutably_bound(&self) -> bool {
+        self.0.as_ref().is_currently_mutably_bound()
+    }
 }
 
 /// Internals of [`GdCell`].
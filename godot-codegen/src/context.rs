@@ -7,7 +7,7 @@
 
 use crate::generator::method_tables::MethodTableKey;
 use crate::generator::notifications;
-use crate::models::domain::{GodotTy, RustTy, TyName};
+use crate::models::domain::{ClassCodegenLevel, GodotTy, RustTy, TyName};
 use crate::models::json::{
     JsonBuiltinClass, JsonBuiltinMethod, JsonClass, JsonClassConstant, JsonClassMethod,
 };
@@ -22,6 +22,7 @@ pub struct Context<'a> {
     builtin_types: HashSet<&'a str>,
     native_structures_types: HashSet<&'a str>,
     singletons: HashSet<&'a str>,
+    class_api_levels: HashMap<&'a str, ClassCodegenLevel>,
     inheritance_tree: InheritanceTree,
     cached_rust_types: HashMap<GodotTy, RustTy>,
     notifications_by_class: HashMap<TyName, Vec<(Ident, i32)>>,
@@ -55,6 +56,12 @@ impl<'a> Context<'a> {
             ctx.native_structures_types.insert(ty_name);
         }
 
+        // API levels are needed to exclude methods referring to excluded classes, so they must be known before any methods are visited.
+        for class in api.classes.iter() {
+            ctx.class_api_levels
+                .insert(class.name.as_str(), util::get_api_level(class));
+        }
+
         let mut engine_classes = HashMap::new();
         for class in api.classes.iter() {
             let class_name = TyName::from_godot(&class.name);
@@ -246,6 +253,11 @@ impl<'a> Context<'a> {
         self.singletons.contains(class_name)
     }
 
+    /// API level of an engine class, or `None` if the class is unknown.
+    pub fn class_api_level(&self, class_name: &str) -> Option<ClassCodegenLevel> {
+        self.class_api_levels.get(class_name).copied()
+    }
+
     pub fn inheritance_tree(&self) -> &InheritanceTree {
         &self.inheritance_tree
     }
@@ -223,6 +223,16 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
                 elem_class: elem_ty.to_string(),
             }
         };
+    } else if let Some(kv_tys) = ty.strip_prefix("typeddictionary::") {
+        // Godot 4.4+ declares key and value types as `typeddictionary::K;V`.
+        let (key_ty, value_ty) = kv_tys
+            .split_once(';')
+            .unwrap_or_else(|| panic!("malformed typed dictionary type {ty:?}"));
+
+        return RustTy::TypedDictionary {
+            key: Box::new(to_rust_type(key_ty, None, ctx)),
+            value: Box::new(to_rust_type(value_ty, None, ctx)),
+        };
     }
 
     // Note: do not check if it's a known engine class, because that will not work in minimal mode (since not all classes are stored)
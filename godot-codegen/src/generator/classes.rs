@@ -14,16 +14,21 @@ use crate::models::domain::{
     ModName, TyName,
 };
 use crate::util::{ident, make_string_name};
-use crate::{conv, util, SubmitFn};
+use crate::{conv, special_cases, util, SubmitFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::path::Path;
+use std::time::Instant;
 
+/// Generates one file per engine class, plus the `mod.rs` file.
+///
+/// If the environment variable `GDEXT_CODEGEN_CLASS_TIMING=1` is set, the time spent on each class is recorded in `watch`.
 pub fn generate_class_files(
     api: &ExtensionApi,
     ctx: &mut Context,
     view: &ApiView,
     gen_path: &Path,
+    watch: &mut godot_bindings::StopWatch,
     submit_fn: &mut SubmitFn,
 ) {
     let _ = std::fs::remove_dir_all(gen_path);
@@ -31,6 +36,12 @@ pub fn generate_class_files(
 
     let mut modules = vec![];
     for class in api.classes.iter() {
+        if special_cases::is_class_level_excluded(class.api_level) {
+            continue;
+        }
+
+        let class_start = Instant::now();
+
         let generated_class = make_class(class, ctx, view);
         let file_contents = generated_class.code;
 
@@ -45,6 +56,11 @@ pub fn generate_class_files(
             inherits_macro_ident: generated_class.inherits_macro_ident,
             is_pub_sidecar: generated_class.has_sidecar_module,
         });
+
+        watch.record_detail(
+            format!("generate_class_file/{}", class.name().godot_ty),
+            class_start.elapsed(),
+        );
     }
 
     let out_path = gen_path.join("mod.rs");
@@ -56,6 +56,8 @@ pub fn make_enum_definition_with(
     let definition = if define_enum {
         // Exhaustive enums are declared as Rust enums.
         if enum_.is_exhaustive {
+            let all_fn = make_enum_all_fn(enum_);
+            let display_impl = make_enum_display_impl(enum_);
             quote! {
                 #[repr(i32)]
                 #[derive(Debug, #( #derives ),* )]
@@ -66,6 +68,9 @@ pub fn make_enum_definition_with(
                 pub enum #name {
                     #( #enumerators )*
                 }
+
+                #all_fn
+                #display_impl
             }
         }
         //
@@ -77,6 +82,9 @@ pub fn make_enum_definition_with(
             });
 
             let debug_impl = make_enum_debug_impl(enum_);
+            let display_impl = make_enum_display_impl(enum_);
+            let bitfield_methods = make_bitfield_methods(enum_);
+            let all_fn = make_enum_all_fn(enum_);
             quote! {
                 #[repr(transparent)]
                 #[derive( #( #derives ),* )]
@@ -89,7 +97,10 @@ pub fn make_enum_definition_with(
                     #( #enumerators )*
                 }
 
+                #bitfield_methods
+                #all_fn
                 #debug_impl
+                #display_impl
             }
         }
     } else {
@@ -101,11 +112,14 @@ pub fn make_enum_definition_with(
         let engine_trait_impl = make_enum_engine_trait_impl(enum_);
         let index_enum_impl = make_enum_index_impl(enum_);
         let bitwise_impls = make_enum_bitwise_operators(enum_);
+        // Orphan rule: std traits can only be implemented in the crate that defines the enum.
+        let from_str_impl = define_enum.then(|| make_enum_from_str_impl(enum_));
 
         quote! {
             #engine_trait_impl
             #index_enum_impl
             #bitwise_impls
+            #from_str_impl
 
             impl crate::meta::GodotConvert for #name {
                 type Via = #ord_type;
@@ -181,6 +195,79 @@ fn make_enum_debug_impl(enum_: &Enum) -> TokenStream {
     }
 }
 
+/// Implement `Display` trait for the enum, writing the Godot enumerator name.
+///
+/// Unlike `Debug`, this uses the stable name as it appears in Godot (e.g. `KEY_ESCAPE` rather than `ESCAPE`).
+fn make_enum_display_impl(enum_: &Enum) -> TokenStream {
+    let enum_name = &enum_.name;
+
+    let enumerators = enum_.enumerators.iter().map(|enumerator| {
+        let Enumerator {
+            name, godot_name, ..
+        } = enumerator;
+        quote! {
+            Self::#name => #godot_name,
+        }
+    });
+
+    let ord_expr = if enum_.is_exhaustive {
+        quote! { *self as i32 }
+    } else {
+        quote! { self.ord }
+    };
+
+    quote! {
+        impl std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                // Duplicate ordinals map to the first enumerator; unknown values (e.g. combined flags) print their ordinal.
+                #[allow(unreachable_patterns)]
+                let enumerator = match *self {
+                    #( #enumerators )*
+                    _ => return write!(f, "{}", #ord_expr),
+                };
+
+                f.write_str(enumerator)
+            }
+        }
+    }
+}
+
+/// Implement `FromStr` trait for the enum, accepting both Rust and Godot enumerator names.
+fn make_enum_from_str_impl(enum_: &Enum) -> TokenStream {
+    let enum_name = &enum_.name;
+    let enum_name_str = enum_name.to_string();
+
+    let enumerators = enum_.enumerators.iter().map(|enumerator| {
+        let Enumerator {
+            name, godot_name, ..
+        } = enumerator;
+        let name_str = name.to_string();
+
+        if &name_str == godot_name {
+            quote! { #name_str => Ok(Self::#name), }
+        } else {
+            quote! { #name_str | #godot_name => Ok(Self::#name), }
+        }
+    });
+
+    quote! {
+        impl std::str::FromStr for #enum_name {
+            type Err = crate::meta::error::ConvertError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                // Enumerators with duplicate ordinals or overlapping names are matched by their first occurrence.
+                #[allow(unreachable_patterns)]
+                match s {
+                    #( #enumerators )*
+                    _ => Err(crate::meta::error::ConvertError::new(format!(
+                        "unknown enumerator {s:?} for enum {}", #enum_name_str
+                    ))),
+                }
+            }
+        }
+    }
+}
+
 /// Creates an implementation of the engine trait for the given enum.
 ///
 /// This will implement the trait returned by [`Enum::engine_trait`].
@@ -276,6 +363,99 @@ fn make_enum_bitwise_operators(enum_: &Enum) -> TokenStream {
         TokenStream::new()
     }
 }
+
+/// Creates an inherent `all()` function, returning every distinct enumerator of a (non-bitfield) enum.
+///
+/// Must be generated alongside the type definition, as inherent impls cannot live in another crate.
+fn make_enum_all_fn(enum_: &Enum) -> TokenStream {
+    if enum_.is_bitfield {
+        return TokenStream::new();
+    }
+
+    let name = &enum_.name;
+
+    // The `*_MAX` enumerator of index enums denotes the number of enumerators, not a value of its own.
+    let max_ord = enum_.find_index_enum_max().map(|max| max as i32);
+
+    // Keep the first enumerator for each ordinal; later ones are aliases (e.g. renames or deprecations).
+    let mut seen_ords = std::collections::HashSet::new();
+    let enumerators = enum_.enumerators.iter().filter_map(|enumerator| {
+        let EnumeratorValue::Enum(ord) = enumerator.value else {
+            return None;
+        };
+
+        if Some(ord) == max_ord || !seen_ords.insert(ord) {
+            return None;
+        }
+
+        let name = &enumerator.name;
+        Some(quote! { Self::#name })
+    });
+
+    quote! {
+        impl #name {
+            /// Returns all distinct enumerators, in declaration order.
+            ///
+            /// Aliases (enumerators sharing an ordinal with an earlier one) and a trailing `*_MAX` count enumerator are excluded.
+            pub fn all() -> &'static [Self] {
+                &[ #( #enumerators ),* ]
+            }
+        }
+    }
+}
+
+/// Creates inherent methods to query and modify individual flags of a bitfield.
+///
+/// Must be generated alongside the type definition, as inherent impls cannot live in another crate.
+fn make_bitfield_methods(enum_: &Enum) -> TokenStream {
+    if !enum_.is_bitfield {
+        return TokenStream::new();
+    }
+
+    let name = &enum_.name;
+
+    quote! {
+        impl #name {
+            /// Returns `true` if all flags set in `other` are also set in `self`.
+            ///
+            /// Unlike [`EngineBitfield::is_set()`][crate::obj::EngineBitfield::is_set], which checks for _any_ common flag.
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.ord & other.ord == other.ord
+            }
+
+            /// Sets all flags in `other`.
+            #[inline]
+            pub fn insert(&mut self, other: Self) {
+                self.ord |= other.ord;
+            }
+
+            /// Clears all flags in `other`.
+            #[inline]
+            pub fn remove(&mut self, other: Self) {
+                self.ord &= !other.ord;
+            }
+
+            /// Inverts all flags in `other`.
+            #[inline]
+            pub fn toggle(&mut self, other: Self) {
+                self.ord ^= other.ord;
+            }
+
+            /// Returns an iterator over the individual flags set in `self`, from lowest to highest bit.
+            ///
+            /// Each item has exactly one bit set. Bits without a named constant are yielded as well.
+            pub fn iter(self) -> impl Iterator<Item = Self> {
+                let ord = self.ord;
+                (0..u64::BITS)
+                    .map(|bit| 1u64 << bit)
+                    .filter(move |mask| ord & mask != 0)
+                    .map(|ord| Self { ord })
+            }
+        }
+    }
+}
+
 /// Returns the documentation for the given enum.
 ///
 /// Each string is one line of documentation, usually this needs to be wrapped in a `#[doc = ..]`.
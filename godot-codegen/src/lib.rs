@@ -51,9 +51,31 @@ fn write_file(path: &Path, contents: String) {
         .unwrap_or_else(|e| panic!("failed to write code file to {};\n\t{}", path.display(), e));
 }
 
+/// Creates the function that writes generated code to files.
+///
+/// Formatting with the built-in formatter is decided when the build script runs, not through a Cargo feature: setting the environment
+/// variable `GDEXT_CODEGEN_FMT=0` writes the raw token stream instead, which can be useful when debugging generated code. Any other
+/// value (or none) formats. Changing the variable re-runs the build script, regenerating the bindings on the next build.
 #[cfg(not(feature = "codegen-rustfmt"))]
-fn submit_fn(path: PathBuf, tokens: TokenStream) {
-    write_file(&path, formatter::format_tokens(tokens));
+fn make_submit_fn() -> impl FnMut(PathBuf, TokenStream) {
+    println!("cargo:rerun-if-env-changed=GDEXT_CODEGEN_FMT");
+    let format = !matches!(std::env::var("GDEXT_CODEGEN_FMT").as_deref(), Ok("0"));
+
+    move |path, tokens| write_file(&path, render_tokens(tokens, format))
+}
+
+#[cfg(not(feature = "codegen-rustfmt"))]
+fn render_tokens(tokens: TokenStream, format: bool) -> String {
+    if format {
+        formatter::format_tokens(tokens)
+    } else {
+        tokens.to_string()
+    }
+}
+
+#[cfg(feature = "codegen-rustfmt")]
+fn make_submit_fn() -> impl FnMut(PathBuf, TokenStream) {
+    rustfmt::submit_fn
 }
 
 #[cfg(feature = "codegen-rustfmt")]
@@ -90,13 +112,14 @@ mod rustfmt {
 }
 
 #[cfg(feature = "codegen-rustfmt")]
-pub(crate) use rustfmt::*;
+pub(crate) use rustfmt::rustfmt_files;
 
 pub fn generate_sys_files(
     sys_gen_path: &Path,
     h_path: &Path,
     watch: &mut godot_bindings::StopWatch,
 ) {
+    let mut submit_fn = make_submit_fn();
     let json_api = load_extension_api(watch);
 
     let mut ctx = Context::build_from_api(&json_api);
@@ -140,6 +163,7 @@ pub fn generate_sys_files(
 
 pub fn generate_core_files(core_gen_path: &Path) {
     let mut watch = godot_bindings::StopWatch::start();
+    let mut submit_fn = make_submit_fn();
 
     generate_core_mod_file(core_gen_path, &mut submit_fn);
 
@@ -168,6 +192,7 @@ pub fn generate_core_files(core_gen_path: &Path) {
         &mut ctx,
         &view,
         &core_gen_path.join("classes"),
+        &mut watch,
         &mut submit_fn,
     );
     watch.record("generate_class_files");
@@ -593,6 +593,14 @@ pub enum RustTy {
     /// `Array<i32>`
     BuiltinArray(TokenStream),
 
+    /// `Dictionary` with key and value types, declared in Godot 4.4+ as `typeddictionary::K;V`.
+    ///
+    /// There is no typed dictionary in Rust yet, so this is emitted as the untyped `Dictionary`.
+    TypedDictionary {
+        key: Box<RustTy>,
+        value: Box<RustTy>,
+    },
+
     /// C-style raw pointer to a `RustTy`.
     RawPointer { inner: Box<RustTy>, is_const: bool },
 
@@ -643,6 +651,7 @@ impl ToTokens for RustTy {
         match self {
             RustTy::BuiltinIdent(ident) => ident.to_tokens(tokens),
             RustTy::BuiltinArray(path) => path.to_tokens(tokens),
+            RustTy::TypedDictionary { .. } => quote! { Dictionary }.to_tokens(tokens),
             RustTy::RawPointer {
                 inner,
                 is_const: true,
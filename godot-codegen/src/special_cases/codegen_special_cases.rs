@@ -10,6 +10,7 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
+use crate::models::domain::ClassCodegenLevel;
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
 use crate::special_cases;
 
@@ -29,38 +30,60 @@ pub(crate) fn is_class_excluded(_godot_class_name: &str) -> bool {
     false
 }
 
-#[cfg(not(feature = "codegen-full"))]
+/// Whether all classes of a given API level are skipped during codegen.
+///
+/// Methods and utility functions referring to classes of such a level are excluded as well, see `is_type_excluded()`.
+pub(crate) fn is_class_level_excluded(api_level: ClassCodegenLevel) -> bool {
+    cfg!(feature = "codegen-no-editor") && api_level == ClassCodegenLevel::Editor
+}
+
 fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
     use crate::conv;
     use crate::models::domain::RustTy;
 
-    fn is_rust_type_excluded(ty: &RustTy) -> bool {
+    // Nothing to exclude; avoids type conversion for every method.
+    if cfg!(feature = "codegen-full") && !cfg!(feature = "codegen-no-editor") {
+        return false;
+    }
+
+    fn is_engine_class_excluded(class_name: &str, ctx: &Context) -> bool {
+        is_class_excluded(class_name)
+            || ctx
+                .class_api_level(class_name)
+                .map_or(false, is_class_level_excluded)
+    }
+
+    fn is_rust_type_excluded(ty: &RustTy, ctx: &Context) -> bool {
         match ty {
             RustTy::BuiltinIdent(_) => false,
             RustTy::BuiltinArray(_) => false,
-            RustTy::RawPointer { inner, .. } => is_rust_type_excluded(inner),
-            RustTy::EngineArray { elem_class, .. } => is_class_excluded(elem_class.as_str()),
+            RustTy::TypedDictionary { key, value } => {
+                is_rust_type_excluded(key, ctx) || is_rust_type_excluded(value, ctx)
+            }
+            RustTy::RawPointer { inner, .. } => is_rust_type_excluded(inner, ctx),
+            RustTy::EngineArray { elem_class, .. } => {
+                is_engine_class_excluded(elem_class.as_str(), ctx)
+            }
             RustTy::EngineEnum {
                 surrounding_class, ..
             } => match surrounding_class.as_ref() {
                 None => false,
-                Some(class) => is_class_excluded(class.as_str()),
+                Some(class) => is_engine_class_excluded(class.as_str(), ctx),
             },
             RustTy::EngineBitfield {
                 surrounding_class, ..
             } => match surrounding_class.as_ref() {
                 None => false,
-                Some(class) => is_class_excluded(class.as_str()),
+                Some(class) => is_engine_class_excluded(class.as_str(), ctx),
             },
-            RustTy::EngineClass { inner_class, .. } => is_class_excluded(&inner_class.to_string()),
+            RustTy::EngineClass { inner_class, .. } => {
+                is_engine_class_excluded(&inner_class.to_string(), ctx)
+            }
         }
     }
-    is_rust_type_excluded(&conv::to_rust_type(ty, None, ctx))
-}
 
-#[cfg(feature = "codegen-full")]
-fn is_type_excluded(_ty: &str, _ctx: &mut Context) -> bool {
-    false
+    let rust_ty = conv::to_rust_type(ty, None, ctx);
+    is_rust_type_excluded(&rust_ty, ctx)
 }
 
 pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Context) -> bool {
@@ -69,7 +92,7 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
         // so passing in a class name while checking for any types is fine.
         let class_deleted = special_cases::is_godot_type_deleted(ty);
 
-        // Then also check if the type is excluded from codegen (due to current Cargo feature. RHS is always false in full-codegen.
+        // Then also check if the type is excluded from codegen (due to current Cargo features, e.g. minimal or no-editor codegen).
         class_deleted || is_type_excluded(ty, _ctx)
     };
 
@@ -91,15 +114,6 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
     false
 }
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_utility_function_excluded(
-    _function: &JsonUtilityFunction,
-    _ctx: &mut Context,
-) -> bool {
-    false
-}
-
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_utility_function_excluded(
     function: &JsonUtilityFunction,
     ctx: &mut Context,
@@ -23,7 +23,7 @@
 
 #![allow(clippy::match_like_matches_macro)] // if there is only one rule
 
-use crate::models::domain::TyName;
+use crate::models::domain::{ClassCodegenLevel, TyName};
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::Context;
@@ -76,6 +76,11 @@ pub fn is_class_deleted(class_name: &TyName) -> bool {
         || is_godot_type_deleted(&class_name.godot_ty)
 }
 
+/// Whether classes of this API level are excluded entirely, e.g. editor classes with feature `codegen-no-editor`.
+pub fn is_class_level_excluded(api_level: ClassCodegenLevel) -> bool {
+    codegen_special_cases::is_class_level_excluded(api_level)
+}
+
 pub fn is_godot_type_deleted(godot_ty: &str) -> bool {
     // Note: parameter can be a class or builtin name, but also something like "enum::AESContext.Mode".
 
@@ -11,6 +11,8 @@
 use crate::conv;
 use crate::generator::native_structures::parse_native_structures_format;
 use crate::generator::native_structures::NativeStructuresField;
+use crate::models::domain::ClassCodegenLevel;
+use crate::special_cases;
 
 #[test]
 fn test_pascal_conversion() {
@@ -171,3 +173,162 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_editor_classes_and_referencing_methods_excluded() {
+    use crate::context::Context;
+    use crate::models::domain::TyName;
+    use crate::models::json::JsonExtensionApi;
+    use nanoserde::DeJson;
+
+    // Minimal API: `Node` (scene level) has methods referring to `EditorPlugin` (editor level).
+    let json = r#"{
+        "header": {
+            "version_major": 4, "version_minor": 2, "version_patch": 0,
+            "version_status": "stable", "version_build": "official", "version_full_name": "Godot Engine v4.2.stable.official"
+        },
+        "builtin_class_sizes": [],
+        "builtin_classes": [],
+        "classes": [
+            {
+                "name": "Object", "is_refcounted": false, "is_instantiable": true, "api_type": "core",
+                "constants": [{ "name": "NOTIFICATION_POSTINITIALIZE", "value": 0 }]
+            },
+            {
+                "name": "Node", "is_refcounted": false, "is_instantiable": true, "inherits": "Object", "api_type": "core",
+                "methods": [
+                    {
+                        "name": "get_plugin", "is_const": true, "is_vararg": false, "is_static": false, "is_virtual": false,
+                        "hash": 1, "return_value": { "type": "EditorPlugin" }
+                    },
+                    {
+                        "name": "set_dock_slot", "is_const": false, "is_vararg": false, "is_static": false, "is_virtual": false,
+                        "hash": 2, "arguments": [{ "name": "slot", "type": "enum::EditorPlugin.DockSlot" }]
+                    },
+                    {
+                        "name": "get_child_count", "is_const": true, "is_vararg": false, "is_static": false, "is_virtual": false,
+                        "hash": 3, "return_value": { "type": "int", "meta": "int32" }
+                    }
+                ]
+            },
+            {
+                "name": "EditorPlugin", "is_refcounted": false, "is_instantiable": true, "inherits": "Node", "api_type": "editor"
+            }
+        ],
+        "global_enums": [],
+        "utility_functions": [],
+        "native_structures": [],
+        "singletons": []
+    }"#;
+
+    let api = JsonExtensionApi::deserialize_json(json).expect("valid API JSON");
+    let mut ctx = Context::build_from_api(&api);
+
+    // Classes like `EditorPlugin` and everything referring to them are only emitted if the `codegen-no-editor` feature is off.
+    let editor_excluded = cfg!(feature = "codegen-no-editor");
+
+    let level_excluded = |class: &str| {
+        let level = ctx.class_api_level(class).expect("class is registered");
+        special_cases::is_class_level_excluded(level)
+    };
+    assert_eq!(level_excluded("EditorPlugin"), editor_excluded);
+    assert!(!level_excluded("Node"));
+    assert!(!level_excluded("Object"));
+
+    let node = TyName::from_godot("Node");
+    let node_methods = api.classes[1].methods.as_ref().unwrap();
+    let method_deleted = |index: usize, ctx: &mut Context| {
+        special_cases::is_class_method_deleted(&node, &node_methods[index], ctx)
+    };
+
+    assert_eq!(method_deleted(0, &mut ctx), editor_excluded, "return type");
+    assert_eq!(
+        method_deleted(1, &mut ctx),
+        editor_excluded,
+        "enum parameter"
+    );
+    assert!(!method_deleted(2, &mut ctx), "unrelated method");
+}
+
+#[test]
+fn test_api_level_from_json() {
+    use crate::models::json::JsonClass;
+    use nanoserde::DeJson;
+
+    // Classification relies on `api_type` from the JSON, not on class name heuristics.
+    fn level(name: &str, inherits: &str, api_type: &str) -> ClassCodegenLevel {
+        let json = format!(
+            r#"{{ "name": "{name}", "is_refcounted": false, "is_instantiable": true, "inherits": "{inherits}", "api_type": "{api_type}" }}"#
+        );
+        let class = JsonClass::deserialize_json(&json).expect("valid class JSON");
+
+        crate::util::get_api_level(&class)
+    }
+
+    // Despite the "Extension" suffix (like virtual extension classes), not editor-only.
+    assert!(level("OpenXRAPIExtension", "RefCounted", "core") == ClassCodegenLevel::Scene);
+    assert!(level("EditorPlugin", "Node", "editor") == ClassCodegenLevel::Editor);
+}
+
+#[test]
+fn test_typed_dictionary_return() {
+    use crate::context::Context;
+    use crate::models::domain::{FnReturn, RustTy};
+    use crate::models::json::JsonClassMethod;
+    use nanoserde::DeJson;
+    use quote::ToTokens;
+
+    // Excerpt of a Godot 4.4+ method returning a typed dictionary.
+    let json = r#"{
+        "name": "get_typed_dict",
+        "is_const": true,
+        "is_vararg": false,
+        "is_static": false,
+        "is_virtual": false,
+        "hash": 3995934104,
+        "return_value": { "type": "typeddictionary::int;Node" }
+    }"#;
+
+    let method = JsonClassMethod::deserialize_json(json).expect("valid method JSON");
+    let mut ctx = Context::default();
+    let ret = FnReturn::new(&method.return_value, &mut ctx);
+
+    let Some(RustTy::TypedDictionary { key, value }) = &ret.type_ else {
+        panic!("typed dictionary not recognized: {:?}", ret.type_);
+    };
+
+    assert_eq!(key.to_token_stream().to_string(), "i64");
+    assert!(
+        matches!(value.as_ref(), RustTy::EngineClass { inner_class, .. } if inner_class == "Node"),
+        "value type not preserved: {value:?}"
+    );
+    assert_eq!(ret.decl.to_string(), "-> Dictionary");
+}
+
+#[cfg(not(feature = "codegen-rustfmt"))]
+#[test]
+fn test_formatted_and_raw_tokens_equal() {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+
+    let tokens = quote! {
+        #[doc = "Docs."]
+        pub struct Foo { ord: i32 }
+
+        impl Foo {
+            pub const BAR: Foo = Foo { ord: 1 };
+
+            pub fn get(&self) -> Option<&i32> {
+                match self.ord { 0 => None, _ => Some(&self.ord) }
+            }
+        }
+    };
+
+    let formatted = crate::render_tokens(tokens.clone(), true);
+    let raw = crate::render_tokens(tokens, false);
+    assert_ne!(formatted, raw, "formatter should change layout");
+
+    // Both outputs must parse back into the same token stream.
+    let reparse = |code: &str| code.parse::<TokenStream>().unwrap().to_string();
+    assert_eq!(reparse(&formatted), reparse(&raw));
+}
@@ -101,6 +101,7 @@ impl Aabb {
     }
 
     /// Returns the center of the AABB, which is equal to `position + (size / 2)`.
+    #[doc(alias = "get_center")]
     #[inline]
     pub fn center(self) -> Vector3 {
         self.position + (self.size / 2.0)
@@ -188,6 +189,7 @@ impl Aabb {
     }
 
     /// Returns the normalized longest axis of the AABB.
+    #[doc(alias = "get_longest_axis")]
     #[inline]
     pub fn longest_axis(self) -> Option<Vector3> {
         self.longest_axis_index().map(|axis| match axis {
@@ -198,12 +200,14 @@ impl Aabb {
     }
 
     /// Returns the index of the longest axis of the AABB (according to Vector3's AXIS_* constants).
+    #[doc(alias = "get_longest_axis_index")]
     #[inline]
     pub fn longest_axis_index(self) -> Option<Vector3Axis> {
         self.size.max_axis()
     }
 
     /// Returns the scalar length of the longest axis of the AABB.
+    #[doc(alias = "get_longest_axis_size")]
     #[inline]
     pub fn longest_axis_size(self) -> real {
         self.size.x.max(self.size.y.max(self.size.z))
@@ -262,6 +266,7 @@ impl Aabb {
             && self.position.y <= end_b.y
             && end.y >= b.position.y
             && self.position.z <= end_b.z
+            && end.z >= b.position.z
     }
 
     /// Checks whether two AABBs have at least one _inner_ point in common (not on the borders).
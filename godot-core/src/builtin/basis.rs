@@ -843,4 +843,18 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&basis, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_rows() {
+        // Serialized row by row, regardless of construction.
+        let basis = Basis::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        let expected_json = "{\"rows\":[{\"x\":1.0,\"y\":4.0,\"z\":7.0},{\"x\":2.0,\"y\":5.0,\"z\":8.0},{\"x\":3.0,\"y\":6.0,\"z\":9.0}]}";
+
+        crate::builtin::test_utils::roundtrip(&basis, expected_json);
+    }
 }
@@ -22,9 +22,7 @@ use sys::{ffi_methods, GodotFfi};
 /// also be a custom callable, which is usually created from `bind`, `unbind`, or a GDScript lambda. See
 /// [`Callable::is_custom`].
 ///
-/// Currently it is impossible to use `bind` and `unbind` in GDExtension, see [godot-cpp#802].
-///
-/// [godot-cpp#802]: https://github.com/godotengine/godot-cpp/issues/802
+/// Arguments can be bound to an existing callable with [`bind()`](Self::bind) or [`bindv()`](Self::bindv).
 pub struct Callable {
     opaque: sys::types::OpaqueCallable,
 }
@@ -80,6 +78,13 @@ impl Callable {
     /// Callables created through multiple `from_fn()` calls are never equal, even if they refer to the same function. If you want to use
     /// equality, either clone an existing `Callable` instance, or define your own `PartialEq` impl with [`Callable::from_custom`].
     ///
+    /// Such callables can be passed anywhere a `Callable` is expected, e.g. to [`Signal::connect_flags()`][crate::builtin::Signal::connect_flags].
+    ///
+    /// # Threading and lifetimes
+    /// Godot may store the callable for an arbitrary time (e.g. as a signal connection) and invoke it from any thread. The function
+    /// must thus be `'static` and `Send + Sync`: it cannot borrow local state, and shared mutable state needs synchronization,
+    /// such as `Arc<Mutex<T>>` or atomics. The function is dropped together with the last copy of the `Callable`.
+    ///
     /// # Example
     /// ```no_run
     /// # use godot::prelude::*;
@@ -173,8 +178,31 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable at idle time, i.e. at the end of the current frame.
+    ///
+    /// Useful to defer work out of the current call stack, e.g. out of physics callbacks or signal handlers that must not
+    /// modify the emitter right away. The return value of the call is discarded.
+    ///
+    /// _Godot equivalent: `call_deferred`_
+    pub fn call_deferred(&self, arguments: &[Variant]) {
+        // Vararg builtin methods are not yet generated in the inner API (see #382), so dispatch dynamically.
+        self.to_variant().call("call_deferred", arguments);
+    }
+
+    /// Returns a copy of this Callable with one or more arguments bound.
+    ///
+    /// When the returned callable is invoked, the bound arguments are appended _after_ the arguments passed to the call. This is useful
+    /// to pass extra context to signal handlers, see [`Signal::connect_flags()`](crate::builtin::Signal::connect_flags).
+    ///
+    /// _Godot equivalent: `bind`_
+    pub fn bind(&self, arguments: &[Variant]) -> Self {
+        self.bindv(arguments.iter().cloned().collect())
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
+    /// See [`bind()`](Self::bind) for details.
+    ///
     /// _Godot equivalent: `bindv`_
     pub fn bindv(&self, arguments: VariantArray) -> Self {
         self.as_inner().bindv(arguments)
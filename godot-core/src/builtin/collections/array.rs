@@ -159,6 +159,25 @@ impl<T: ArrayElement> Array<T> {
         Self::default()
     }
 
+    /// Collects an iterator of `Result`s into an array, stopping at the first error.
+    ///
+    /// Returns the first `Err` encountered; elements collected up to that point are discarded. Equivalent to
+    /// `iter.into_iter().collect::<Result<Array<T>, E>>()`, but more discoverable and without type annotations.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let parsed = Array::<i64>::try_from_iter(["1", "2", "x"].iter().map(|s| s.parse::<i64>()));
+    /// assert!(parsed.is_err());
+    /// ```
+    pub fn try_from_iter<I, E>(iter: I) -> Result<Self, E>
+    where
+        T: ToGodot,
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        iter.into_iter().collect()
+    }
+
     /// ⚠️ Returns the value at the specified index.
     ///
     /// This replaces the `Index` trait, which cannot be implemented for `Array` as references are not guaranteed to remain valid.
@@ -372,6 +391,58 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.erase(value.to_variant());
     }
 
+    /// Retains only the elements for which `f` returns `true`, removing all others in place.
+    ///
+    /// Preserves the order of retained elements, like [`Vec::retain()`]. Runs in O(n), as remaining elements are moved forward
+    /// and the array is shrunk once at the end.
+    ///
+    /// If `f` panics, the array is left in a valid but unspecified state (elements may be duplicated or missing).
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let mut kept = 0;
+
+        for i in 0..len {
+            let value = self.at(i);
+            if f(&value) {
+                if kept != i {
+                    self.set(kept, value);
+                }
+                kept += 1;
+            }
+        }
+
+        self.shrink(kept);
+    }
+
+    /// Removes consecutive repeated elements, like [`Vec::dedup()`].
+    ///
+    /// If the array is sorted, this removes all duplicates.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        let mut kept = 1;
+        for i in 1..len {
+            let value = self.at(i);
+            if value != self.at(kept - 1) {
+                if kept != i {
+                    self.set(kept, value);
+                }
+                kept += 1;
+            }
+        }
+
+        self.shrink(kept);
+    }
+
     /// Assigns the given value to all elements in the array. This can be used together with
     /// `resize` to create an array with a given size and initialized elements.
     pub fn fill(&mut self, value: &T) {
@@ -398,6 +469,28 @@ impl<T: ArrayElement> Array<T> {
         }
     }
 
+    /// Resizes the array, filling new elements with values returned by `f`.
+    ///
+    /// Like [`resize()`](Self::resize), but `f` is invoked once per added element, so each element can be computed individually.
+    /// If the new size is smaller than the current size, then elements are removed from the end and `f` is never called.
+    ///
+    /// Like [`Vec::resize_with()`].
+    pub fn resize_with<F>(&mut self, new_size: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let original_size = self.len();
+        if new_size <= original_size {
+            self.shrink(new_size);
+            return;
+        }
+
+        // Push one by one rather than resizing upfront, so that a panic in `f` does not leave `NIL` placeholders behind.
+        for _ in original_size..new_size {
+            self.push(f());
+        }
+    }
+
     /// Shrinks the array down to `new_size`.
     ///
     /// This will only change the size of the array if `new_size` is smaller than the current size. Returns `true` if the array was shrunk.
@@ -517,6 +610,31 @@ impl<T: ArrayElement> Array<T> {
         }
     }
 
+    /// Converts the elements to `U`, skipping those that fail conversion.
+    ///
+    /// Returns the successfully converted elements in their original order, together with the number of skipped elements.
+    /// This is meant for best-effort ingestion of heterogeneous data, e.g. a [`VariantArray`] coming from GDScript into a `Vec<i64>`.
+    ///
+    /// ⚠️ This conversion is lossy by design: elements that cannot be converted are dropped, and indices of the remaining elements
+    /// shift accordingly. If every element matters, use [`Vec::from()`] (panics on mismatch) or convert elements individually.
+    pub fn to_vec_lossy<U: FromGodot>(&self) -> (Vec<U>, usize) {
+        let mut vec = Vec::with_capacity(self.len());
+        let mut skipped = 0;
+
+        for index in 0..self.len() {
+            let ptr = self.ptr(index);
+
+            // SAFETY: `ptr` is a live pointer to a variant, since `index` is within bounds.
+            let variant = unsafe { Variant::borrow_var_sys(ptr) };
+            match U::try_from_variant(variant) {
+                Ok(value) => vec.push(value),
+                Err(_) => skipped += 1,
+            }
+        }
+
+        (vec, skipped)
+    }
+
     /// Returns the minimum value contained in the array if all elements are of comparable types.
     ///
     /// If the elements can't be compared or the array is empty, `None` is returned.
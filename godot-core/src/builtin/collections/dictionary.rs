@@ -7,14 +7,17 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, Variant, VariantArray};
-use crate::meta::{FromGodot, ToGodot};
+use crate::builtin::{inner, Array, Variant, VariantArray};
+use crate::meta::error::ConvertError;
+use crate::meta::{ArrayElement, FromGodot, ToGodot};
 use crate::registry::property::{
     builtin_type_string, Export, PropertyHintInfo, TypeStringHint, Var,
 };
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
 use std::{fmt, ptr};
 
@@ -70,9 +73,23 @@ use std::{fmt, ptr};
 /// assert_eq!(dict.get("num"), None);
 /// ```
 ///
+/// # Indexing
+///
+/// GDScript's `dict[key]` syntax has no direct equivalent: Rust's `Index` trait must return a reference, but dictionary values are
+/// shared with every other `Dictionary` handle pointing to the same data. Such a reference could thus be invalidated by modifications
+/// through another handle. Instead, [`at()`][Self::at] (panicking) and [`get()`][Self::get] (returning `Option`) return values by copy,
+/// and [`set()`][Self::set] writes them.
+///
 /// # Thread safety
 ///
 /// The same principles apply as for [`VariantArray`]. Consult its documentation for details.
+///
+/// # Serialization
+///
+/// With the `serde` feature, a dictionary is serialized as a map, with values following the rules of [`Variant`]'s serialization.
+/// Since most self-describing formats (such as JSON) only support string keys, only `String` and `StringName` keys are accepted;
+/// any other key type yields a serialization error rather than being silently converted. Keys are deserialized as `GString`.
+/// Dictionaries and arrays nested more than 256 levels deep, in particular ones containing themselves, yield an error as well.
 pub struct Dictionary {
     opaque: OpaqueDictionary,
 }
@@ -91,6 +108,8 @@ impl Dictionary {
     ///
     /// If you want to check for presence, use [`get()`][Self::get] or [`get_or_nil()`][Self::get_or_nil].
     ///
+    /// This is the closest equivalent to GDScript's `dict[key]`; see [Indexing](#indexing) for why there is no `Index` impl.
+    ///
     /// # Panics
     ///
     /// If there is no value for the given key. Note that this is distinct from a `NIL` value, which is returned as `Variant::nil()`.
@@ -124,6 +143,28 @@ impl Dictionary {
         }
     }
 
+    /// ⚠️ Returns the value for the given key converted to `V`, or panics.
+    ///
+    /// Shorthand for `dict.at(key).to::<V>()`. If you want to check for presence, use [`get_as()`][Self::get_as].
+    ///
+    /// # Panics
+    ///
+    /// If there is no value for the given key, or if the value cannot be converted to `V`.
+    pub fn at_as<K: ToGodot, V: FromGodot>(&self, key: K) -> V {
+        self.at(key).to()
+    }
+
+    /// Returns the value for the given key converted to `V`, or `None` if the key is absent.
+    ///
+    /// Shorthand for `dict.get(key).map(|v| v.to::<V>())`.
+    ///
+    /// # Panics
+    ///
+    /// If the key is present, but its value cannot be converted to `V`.
+    pub fn get_as<K: ToGodot, V: FromGodot>(&self, key: K) -> Option<V> {
+        self.get(key).map(|value| value.to())
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -227,6 +268,24 @@ impl Dictionary {
         old_value
     }
 
+    /// Retains only the key-value pairs for which `f` returns `true`, removing all others.
+    ///
+    /// The keys are snapshotted before any entry is removed, so removal does not interfere with iteration (unlike modifying
+    /// the dictionary during [`iter_shared()`][Self::iter_shared]). `f` is called once for each entry present at the time of the call.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Variant, &Variant) -> bool,
+    {
+        let keys = self.keys_array();
+
+        for key in keys.iter_shared() {
+            let value = self.get_or_nil(key.clone());
+            if !f(&key, &value) {
+                self.as_inner().erase(key);
+            }
+        }
+    }
+
     /// Returns a 32-bit integer hash value representing the dictionary and its contents.
     #[must_use]
     pub fn hash(&self) -> u32 {
@@ -249,6 +308,26 @@ impl Dictionary {
         self.as_inner().values()
     }
 
+    /// Creates a new typed `Array` containing all the keys currently in the dictionary.
+    ///
+    /// Returns `Err` if any key cannot be converted to `K`.
+    pub fn keys_array_typed<K>(&self) -> Result<Array<K>, ConvertError>
+    where
+        K: ArrayElement + FromGodot + ToGodot,
+    {
+        self.keys_shared().map(|key| key.try_to()).collect()
+    }
+
+    /// Creates a new typed `Array` containing all the values currently in the dictionary.
+    ///
+    /// Returns `Err` if any value cannot be converted to `V`.
+    pub fn values_array_typed<V>(&self) -> Result<Array<V>, ConvertError>
+    where
+        V: ArrayElement + FromGodot + ToGodot,
+    {
+        self.values_shared().map(|value| value.try_to()).collect()
+    }
+
     /// Copies all keys and values from `other` into `self`.
     ///
     /// If `overwrite` is true, it will overwrite pre-existing keys.
@@ -259,6 +338,20 @@ impl Dictionary {
         self.as_inner().merge(other, overwrite)
     }
 
+    /// Returns a new dictionary with all keys and values of `self` and `other`, leaving both unchanged.
+    ///
+    /// The result starts as a [shallow copy][Self::duplicate_shallow] of `self`. If `overwrite` is true, values of keys present in
+    /// both dictionaries are taken from `other`; otherwise, they are kept from `self`.
+    ///
+    /// _Godot equivalent: `merged` (Godot 4.3+)_
+    #[doc(alias = "merged")]
+    #[must_use]
+    pub fn merged(&self, other: &Self, overwrite: bool) -> Self {
+        let mut result = self.duplicate_shallow();
+        result.extend_dictionary(other.clone(), overwrite);
+        result
+    }
+
     /// Deep copy, duplicating nested collections.
     ///
     /// All nested arrays and dictionaries are duplicated and will not be shared with the original dictionary.
@@ -311,6 +404,27 @@ impl Dictionary {
         Keys::new(self)
     }
 
+    /// Returns an iterator over the values in a `Dictionary`.
+    ///
+    /// The values are each of type `Variant`. Each value references the original `Dictionary`, but instead of a `&`-reference to values
+    /// as you might expect, the iterator returns a (cheap, shallow) copy of each value.
+    ///
+    /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
+    /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
+    ///
+    /// Use `dict.values_shared().typed::<V>()` to iterate over `V` values instead.
+    pub fn values_shared(&self) -> Values<'_> {
+        Values::new(self)
+    }
+
+    /// Returns the entry for the given key, for in-place manipulation.
+    ///
+    /// Similar to [`HashMap::entry()`][std::collections::HashMap::entry]. See [`DictEntry`][crate::builtin::DictEntry] for the
+    /// available operations.
+    pub fn entry<K: ToGodot>(&mut self, key: K) -> Entry<'_> {
+        Entry::new(self, key.to_variant())
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary {
         inner::InnerDictionary::from_outer(self)
@@ -460,6 +574,119 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
     }
 }
 
+/// Converts a dictionary into a `HashMap`, converting each key and value through [`FromGodot`].
+///
+/// Returns the first conversion error encountered, if any.
+///
+/// For the opposite direction, use `Dictionary::from(&map)` or `map.into_iter().collect::<Dictionary>()`. A `From<HashMap<K, V>>`
+/// impl is not possible, as it would overlap with the `From` impl for iterators over `(&K, &V)`.
+impl<K, V> TryFrom<Dictionary> for HashMap<K, V>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+{
+    type Error = ConvertError;
+
+    fn try_from(dictionary: Dictionary) -> Result<Self, Self::Error> {
+        dictionary
+            .iter_shared()
+            .map(|(key, value)| Ok((key.try_to()?, value.try_to()?)))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Entry for a single key in a [`Dictionary`], which may or may not be present.
+///
+/// Obtained through [`Dictionary::entry()`]. Unlike `HashMap`'s entries, values are returned as (cheap, shallow) copies
+/// instead of references, since the dictionary may be modified through other `Dictionary` handles sharing the same data.
+///
+/// Creating the entry checks once whether the key is present. Each operation then accesses the value through Godot's index operator,
+/// which returns the existing slot or inserts a new one; [`and_modify()`][Self::and_modify] needs a second index operation to write back.
+pub struct Entry<'a> {
+    dictionary: &'a mut Dictionary,
+    key: Variant,
+    is_occupied: bool,
+}
+
+impl<'a> Entry<'a> {
+    fn new(dictionary: &'a mut Dictionary, key: Variant) -> Self {
+        let is_occupied = dictionary.contains_key(key.clone());
+
+        Self {
+            dictionary,
+            key,
+            is_occupied,
+        }
+    }
+
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &Variant {
+        &self.key
+    }
+
+    /// Returns `true` if the dictionary contained the key when the entry was created.
+    pub fn is_occupied(&self) -> bool {
+        self.is_occupied
+    }
+
+    /// Inserts `default` if the key is absent. Returns the value now stored for the key.
+    pub fn or_insert<V: ToGodot>(self, default: V) -> Variant {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default()` if the key is absent. Returns the value now stored for the key.
+    ///
+    /// `default` is only invoked if the key is absent.
+    pub fn or_insert_with<V, F>(self, default: F) -> Variant
+    where
+        V: ToGodot,
+        F: FnOnce() -> V,
+    {
+        // Evaluate before obtaining the pointer, since `default` might modify the dictionary through another handle.
+        let inserted = (!self.is_occupied).then(|| default().to_variant());
+
+        // A single index operation serves both cases: it returns the existing value, or inserts NIL to be overwritten.
+        let ptr = self.dictionary.get_ptr_mut(self.key);
+
+        // SAFETY: `get_ptr_mut()` returns a valid pointer to a value in the dictionary, which is not modified in-between.
+        unsafe {
+            match inserted {
+                Some(value) => {
+                    value.clone().move_into_var_ptr(ptr);
+                    value
+                }
+                None => Variant::borrow_var_sys(sys::to_const_ptr(ptr)).clone(),
+            }
+        }
+    }
+
+    /// If the key is present, calls `f` to modify a copy of its value, then writes the value back. Returns the entry for further chaining.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Variant),
+    {
+        if self.is_occupied {
+            // SAFETY: `get_ptr_mut()` returns a valid pointer to a value in the dictionary; it is only read before `f` runs.
+            let mut value = unsafe {
+                Variant::borrow_var_sys(sys::to_const_ptr(
+                    self.dictionary.get_ptr_mut(self.key.clone()),
+                ))
+            }
+            .clone();
+
+            f(&mut value);
+
+            // Write through a fresh pointer, as `f` may have modified the dictionary through another handle.
+            // SAFETY: as above, the pointer is valid and used immediately.
+            unsafe { value.move_into_var_ptr(self.dictionary.get_ptr_mut(self.key.clone())) };
+        }
+
+        self
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Internal helper for different iterator impls -- not an iterator itself
@@ -591,6 +818,14 @@ impl<'a> Iter<'a> {
     pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
         TypedIter::from_untyped(self)
     }
+
+    /// Creates an iterator that will try to convert each `Variant` key-value pair into a `(K, V)` tuple,
+    /// yielding an error for each pair that fails to convert.
+    ///
+    /// Unlike [`typed()`][Self::typed], this does not panic, so dictionaries with unexpected key or value types can be handled gracefully.
+    pub fn try_typed<K: FromGodot, V: FromGodot>(self) -> TryTypedIter<'a, K, V> {
+        TryTypedIter::from_untyped(self)
+    }
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -649,6 +884,48 @@ impl<'a> Iterator for Keys<'a> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Iterator over values in a [`Dictionary`].
+///
+/// See [`Dictionary::values_shared()`] for more information about iteration over dictionaries.
+pub struct Values<'a> {
+    iter: DictionaryIter<'a>,
+}
+
+impl<'a> Values<'a> {
+    fn new(dictionary: &'a Dictionary) -> Self {
+        Self {
+            iter: DictionaryIter::new(dictionary),
+        }
+    }
+
+    /// Creates an iterator that will convert each `Variant` value into a value of type `V`,
+    /// panicking upon failure to convert.
+    pub fn typed<V: FromGodot>(self) -> TypedValues<'a, V> {
+        TypedValues::from_untyped(self)
+    }
+
+    /// Returns an array of the values
+    pub fn array(self) -> VariantArray {
+        // Can only be called
+        assert!(self.iter.is_first);
+        self.iter.dictionary.values_array()
+    }
+}
+
+impl<'a> Iterator for Values<'a> {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_key_value().map(|(_key, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// [`Dictionary`] iterator that converts each key-value pair into a typed `(K, V)`.
 ///
 /// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
@@ -684,6 +961,41 @@ impl<'a, K: FromGodot, V: FromGodot> Iterator for TypedIter<'a, K, V> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// [`Dictionary`] iterator that tries to convert each key-value pair into a typed `(K, V)`.
+///
+/// See [`Iter::try_typed()`] and [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
+pub struct TryTypedIter<'a, K, V> {
+    iter: DictionaryIter<'a>,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, K, V> TryTypedIter<'a, K, V> {
+    fn from_untyped(value: Iter<'a>) -> Self {
+        Self {
+            iter: value.iter,
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: FromGodot, V: FromGodot> Iterator for TryTypedIter<'a, K, V> {
+    type Item = Result<(K, V), ConvertError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// [`Dictionary`] iterator that converts each key into a typed `K`.
 ///
 /// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
@@ -713,6 +1025,108 @@ impl<'a, K: FromGodot> Iterator for TypedKeys<'a, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// [`Dictionary`] iterator that converts each value into a typed `V`.
+///
+/// See [`Dictionary::values_shared()`] for more information about iteration over dictionaries.
+pub struct TypedValues<'a, V> {
+    iter: DictionaryIter<'a>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, V> TypedValues<'a, V> {
+    fn from_untyped(value: Values<'a>) -> Self {
+        Self {
+            iter: value.iter,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<'a, V: FromGodot> Iterator for TypedValues<'a, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_key_value()
+            .map(|(_key, value)| V::from_variant(&value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use crate::builtin::VariantType;
+    use serde::de::{MapAccess, Visitor};
+    use serde::ser::{Error, SerializeMap};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Dictionary {
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter_shared() {
+                if !matches!(
+                    key.get_type(),
+                    VariantType::STRING | VariantType::STRING_NAME
+                ) {
+                    return Err(S::Error::custom(format!(
+                        "Dictionary key {key} of type {} cannot be serialized; only String and StringName keys are supported",
+                        key.type_name()
+                    )));
+                }
+
+                map.serialize_entry(&key, &value)?;
+            }
+            map.end()
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Dictionary {
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct DictionaryVisitor;
+            impl<'de> Visitor<'de> for DictionaryVisitor {
+                type Value = Dictionary;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a map")
+                }
+
+                fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut dict = Dictionary::new();
+                    while let Some((key, value)) = map.next_entry::<Variant, Variant>()? {
+                        dict.set(key, value);
+                    }
+                    Ok(dict)
+                }
+            }
+
+            deserializer.deserialize_map(DictionaryVisitor)
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::*;
+use crate::meta::error::ByteOffsetError;
 use crate::meta::ToGodot;
 use std::{fmt, ops};
 use sys::types::*;
@@ -20,6 +21,42 @@ use sys::{__GdextString, __GdextType};
 // Many builtin types don't have a #[repr] themselves, but they are used in packed arrays, which assumes certain size and alignment.
 // This is mostly a problem for as_slice(), which reinterprets the FFI representation into the "frontend" type like GString.
 
+/// Element type that can be stored in a packed array, e.g. `i64` for [`PackedInt64Array`].
+///
+/// Allows generic code to map from an element type to its packed array, in particular through [`ToPacked::to_packed()`].
+pub trait PackedArrayElement: Clone + Sized {
+    /// The packed array type storing elements of `Self`.
+    type PackedArray: for<'a> From<&'a [Self]>;
+}
+
+/// Bulk conversion of Rust slices (and thus `Vec<T>`) into packed arrays.
+///
+/// Unlike `Array::from(slice)`, no per-element `Variant` conversion takes place; elements are copied into the Godot buffer directly.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{PackedInt64Array, ToPacked};
+///
+/// let vec: Vec<i64> = vec![1, 2, 3];
+/// let packed: PackedInt64Array = vec.to_packed();
+/// assert_eq!(packed.as_slice(), &[1, 2, 3]);
+/// ```
+pub trait ToPacked {
+    /// The packed array type produced by the conversion.
+    type PackedArray;
+
+    /// Copies all elements into a new packed array.
+    fn to_packed(&self) -> Self::PackedArray;
+}
+
+impl<T: PackedArrayElement> ToPacked for [T] {
+    type PackedArray = T::PackedArray;
+
+    fn to_packed(&self) -> Self::PackedArray {
+        T::PackedArray::from(self)
+    }
+}
+
 /// Defines and implements a single packed array type. This macro is not hygienic and is meant to
 /// be used only in the current module.
 macro_rules! impl_packed_array {
@@ -239,6 +276,7 @@ macro_rules! impl_packed_array {
             /// Returns a shared Rust slice of the array.
             ///
             /// The resulting slice can be further subdivided or converted into raw pointers.
+            /// No copy is made: the slice borrows the array's underlying buffer directly.
             ///
             /// See also [`as_mut_slice`][Self::as_mut_slice] to get exclusive slices, and
             /// [`subarray`][Self::subarray] to get a sub-array as a copy.
@@ -434,6 +472,8 @@ macro_rules! impl_packed_array {
         }
 
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given slice.")]
+        ///
+        /// The elements are copied into a newly allocated Godot buffer.
         impl From<&[$Element]> for $PackedArray {
             fn from(slice: &[$Element]) -> Self {
                 let mut array = Self::new();
@@ -442,19 +482,17 @@ macro_rules! impl_packed_array {
                     return array;
                 }
                 array.resize(len);
-                let ptr = array.ptr_mut(0);
-                for (i, element) in slice.iter().enumerate() {
-                    // SAFETY: The array contains exactly `len` elements, stored contiguously in memory.
-                    unsafe {
-                        // `GString` does not implement `Copy` so we have to call `.clone()`
-                        // here.
-                        *ptr.offset(to_isize(i)) = element.clone();
-                    }
-                }
+
+                // Bulk copy; compiles down to a memcpy for `Copy` element types. `GString` is cloned element-wise.
+                array.as_mut_slice().clone_from_slice(slice);
                 array
             }
         }
 
+        impl PackedArrayElement for $Element {
+            type PackedArray = $PackedArray;
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from an iterator.")]
         impl FromIterator<$Element> for $PackedArray {
             fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
@@ -514,10 +552,12 @@ macro_rules! impl_packed_array {
         impl $crate::registry::property::Export for $PackedArray {
             fn default_export_info() -> $crate::registry::property::PropertyHintInfo {
                 // In 4.3 Godot can (and does) use type hint strings for packed arrays, see https://github.com/godotengine/godot/pull/82952.
+                // Unlike `Array<T>`, the element hint is the plain builtin type: the element width is fixed by the packed array itself,
+                // so integer range hints from `TypeStringHint` are not applied (this also matches GDScript).
                 if sys::GdextBuild::since_api("4.3") {
                     $crate::registry::property::PropertyHintInfo {
                         hint: $crate::global::PropertyHint::TYPE_STRING,
-                        hint_string: <$Element as $crate::registry::property::TypeStringHint>::type_string().into(),
+                        hint_string: $crate::registry::property::builtin_type_string::<$Element>().into(),
                     }
                 } else {
                     $crate::registry::property::PropertyHintInfo::with_hint_none(
@@ -529,9 +569,85 @@ macro_rules! impl_packed_array {
     }
 }
 
+// Helper macro to declare a pair of bounds-checked `encode_*`/`decode_*` methods on `PackedByteArray`.
+macro_rules! declare_encode_decode {
+    // $Via is the type Godot uses in its API (i64 or f64), $godot_encode/$godot_decode are the Godot method names.
+    ($Ty:ty, $bytes:literal, $encode_fn:ident, $decode_fn:ident, $godot_encode:ident, $godot_decode:ident, $Via:ty) => {
+        #[doc = concat!("Encodes `", stringify!($Ty), "` as ", stringify!($bytes), " byte(s) at position `byte_offset`.")]
+        ///
+        /// Returns an error if there is not enough space left to write the value, and does nothing in that case.
+        ///
+        /// Byte order is always little-endian, independent of the platform.
+        ///
+        #[doc = concat!("Equivalent to `", stringify!($godot_encode), "()` in GDScript.")]
+        #[allow(clippy::unnecessary_cast)] // Cast is identity for some types.
+        pub fn $encode_fn(&mut self, byte_offset: usize, value: $Ty) -> Result<(), ByteOffsetError> {
+            self.check_byte_offset(byte_offset, $bytes)?;
+
+            self.as_inner().$godot_encode(to_i64(byte_offset), value as $Via);
+            Ok(())
+        }
+
+        #[doc = concat!("Decodes `", stringify!($Ty), "` from ", stringify!($bytes), " byte(s) at position `byte_offset`.")]
+        ///
+        /// Returns an error if there are not enough bytes left to read the value.
+        ///
+        /// Byte order is always little-endian, independent of the platform.
+        ///
+        #[doc = concat!("Equivalent to `", stringify!($godot_decode), "()` in GDScript.")]
+        #[allow(clippy::unnecessary_cast)]
+        pub fn $decode_fn(&self, byte_offset: usize) -> Result<$Ty, ByteOffsetError> {
+            self.check_byte_offset(byte_offset, $bytes)?;
+
+            let decoded: $Via = self.as_inner().$godot_decode(to_i64(byte_offset));
+            Ok(decoded as $Ty)
+        }
+    };
+}
+
 // Helper macro to only include specific functions in the code if the Packed*Array provides the function.
 macro_rules! impl_specific_packed_array_functions {
     (PackedByteArray) => {
+        fn check_byte_offset(
+            &self,
+            byte_offset: usize,
+            value_size: usize,
+        ) -> Result<(), ByteOffsetError> {
+            let len = self.len();
+            if len < value_size || byte_offset > len - value_size {
+                return Err(ByteOffsetError::new(byte_offset, value_size, len));
+            }
+
+            Ok(())
+        }
+
+        declare_encode_decode!(u8, 1, encode_u8, decode_u8, encode_u8, decode_u8, i64);
+        declare_encode_decode!(i8, 1, encode_i8, decode_i8, encode_s8, decode_s8, i64);
+        declare_encode_decode!(u16, 2, encode_u16, decode_u16, encode_u16, decode_u16, i64);
+        declare_encode_decode!(i16, 2, encode_i16, decode_i16, encode_s16, decode_s16, i64);
+        declare_encode_decode!(u32, 4, encode_u32, decode_u32, encode_u32, decode_u32, i64);
+        declare_encode_decode!(i32, 4, encode_i32, decode_i32, encode_s32, decode_s32, i64);
+        declare_encode_decode!(u64, 8, encode_u64, decode_u64, encode_u64, decode_u64, i64);
+        declare_encode_decode!(i64, 8, encode_i64, decode_i64, encode_s64, decode_s64, i64);
+        declare_encode_decode!(
+            f32,
+            4,
+            encode_f32,
+            decode_f32,
+            encode_float,
+            decode_float,
+            f64
+        );
+        declare_encode_decode!(
+            f64,
+            8,
+            encode_f64,
+            decode_f64,
+            encode_double,
+            decode_double,
+            f64
+        );
+
         /// Returns a copy of the data converted to a `PackedFloat32Array`, where each block of 4 bytes has been converted to a 32-bit float.
         ///
         /// The size of the input array must be a multiple of 4 (size of 32-bit float). The size of the new array will be `byte_array.size() / 4`.
@@ -71,6 +71,15 @@ impl Color {
         Color::from_rgba8(r, g, b, a)
     }
 
+    /// Constructs a new `Color` from a 32-bits value in `0xRRGGBBAA` format.
+    ///
+    /// Shorthand for [`Color::from_u32_rgba`] with [`ColorChannelOrder::RGBA`].
+    ///
+    /// _Godot equivalent: `Color.hex`_
+    pub fn from_hex(rgba: u32) -> Self {
+        Self::from_u32_rgba(rgba, ColorChannelOrder::RGBA)
+    }
+
     /// Constructs a new `Color` from a 64-bits value with the given channel `order`.
     ///
     /// _Godot equivalent: `Color.hex64`, if `ColorChannelOrder::Rgba` is used_
@@ -583,4 +592,13 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&color, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_components() {
+        let color = super::Color::from_rgba(0.25, 0.5, 0.75, 0.125);
+        let expected_json = "{\"r\":0.25,\"g\":0.5,\"b\":0.75,\"a\":0.125}";
+
+        crate::builtin::test_utils::roundtrip(&color, expected_json);
+    }
 }
@@ -170,7 +170,9 @@ impl Rect2 {
         point.abs() == point && point.x < self.size.x && point.y < self.size.y
     }
 
-    /// Returns the intersection of this Rect2 and `b`. If the rectangles do not intersect, an empty Rect2 is returned.
+    /// Returns the intersection of this Rect2 and `b`.
+    ///
+    /// If the rectangles do not intersect, `None` is returned. Rectangles that only touch each other yield a rect without area.
     #[inline]
     pub fn intersection(self, b: Self) -> Option<Self> {
         if !self.intersects(b) {
@@ -10,12 +10,13 @@ use std::ptr;
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant, VariantArray};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -64,18 +65,36 @@ impl Signal {
     /// Connects this signal to the specified callable.
     ///
     /// Optional flags can be also added to configure the connection's behavior (see [`ConnectFlags`](crate::classes::object::ConnectFlags) constants).
-    /// You can provide additional arguments to the connected callable by using `Callable::bind`.
+    /// You can provide additional arguments to the connected callable by using [`Callable::bind()`].
     ///
     /// A signal can only be connected once to the same [`Callable`]. If the signal is already connected,
     /// returns [`Error::ERR_INVALID_PARAMETER`] and
     /// pushes an error message, unless the signal is connected with [`ConnectFlags::REFERENCE_COUNTED`](crate::classes::object::ConnectFlags::REFERENCE_COUNTED).
     /// To prevent this, use [`Self::is_connected`] first to check for existing connections.
+    #[deprecated = "Use `Signal::connect_flags()`, which takes typed `ConnectFlags` instead of an integer."]
     pub fn connect(&self, callable: Callable, flags: i64) -> Error {
         let error = self.as_inner().connect(callable, flags);
 
         Error::from_godot(error as i32)
     }
 
+    /// Connects this signal to the specified callable, configuring the connection with [`ConnectFlags`].
+    ///
+    /// Flags can be combined with `|`, e.g. `ConnectFlags::ONE_SHOT | ConnectFlags::DEFERRED`. If no flags are needed,
+    /// pass `ConnectFlags::from_ord(0)`.
+    /// You can provide additional arguments to the connected callable by using [`Callable::bind()`].
+    ///
+    /// A signal can only be connected once to the same [`Callable`]. If the signal is already connected,
+    /// returns [`Error::ERR_INVALID_PARAMETER`] and pushes an error message, unless the signal is connected with
+    /// [`ConnectFlags::REFERENCE_COUNTED`]. To prevent this, use [`Self::is_connected`] first to check for existing connections.
+    ///
+    /// _Godot equivalent: `connect`_
+    pub fn connect_flags(&self, callable: Callable, flags: ConnectFlags) -> Error {
+        let error = self.as_inner().connect(callable, flags.ord() as i64);
+
+        Error::from_godot(error as i32)
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -94,6 +113,51 @@ impl Signal {
         object.emit_signal(self.name(), varargs);
     }
 
+    /// Emits this signal with statically typed arguments, given as a tuple.
+    ///
+    /// Each tuple element is converted with [`ToGodot`], so no manual `to_variant()` calls are needed:
+    /// `signal.emit_tuple((42, GString::from("label")))`. Tuples with 2 to 8 elements are supported; for a single argument,
+    /// use [`emit()`](Self::emit) with `&[arg.to_variant()]`.
+    ///
+    /// The argument types are not checked against the signal's declaration, same as for `emit()`.
+    pub fn emit_tuple<A>(&self, args: A)
+    where
+        A: ToGodot<Via = VariantArray>,
+    {
+        let args: Vec<Variant> = args.to_godot().iter_shared().collect();
+        self.emit(&args);
+    }
+
+    /// Emits this signal at idle time, i.e. at the end of the current frame.
+    ///
+    /// Connected Callables are not triggered immediately; this is useful when emitting from contexts where immediate
+    /// reactions are undesired, such as physics callbacks. If the signal's object is freed before idle time, nothing is emitted.
+    ///
+    /// _Godot equivalent: `call_deferred("emit_signal", ...)`_
+    pub fn emit_deferred(&self, varargs: &[Variant]) {
+        let Some(mut object) = self.object() else {
+            return;
+        };
+
+        let mut args = Vec::with_capacity(varargs.len() + 1);
+        args.push(self.name().to_variant());
+        args.extend_from_slice(varargs);
+
+        object.call_deferred(StringName::from("emit_signal"), &args);
+    }
+
+    /// Returns a future that resolves with the signal's arguments, the next time this signal is emitted.
+    ///
+    /// This is the Rust counterpart to GDScript's `await signal`. Internally, a one-shot [`Callable`] is connected to the signal;
+    /// if the future is dropped before the signal fires, the connection is removed again.
+    ///
+    /// The future must be polled by an executor of your choice; it is woken on emission. If the signal's object is freed before
+    /// the signal is emitted, the future never resolves.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> SignalFuture {
+        SignalFuture::new(self.clone())
+    }
+
     /// Returns an [`Array`] of connections for this signal.
     ///
     /// Each connection is represented as a Dictionary that contains three entries:
@@ -204,3 +268,132 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Async support
+
+#[cfg(since_api = "4.2")]
+pub use signal_future::SignalFuture;
+
+#[cfg(since_api = "4.2")]
+mod signal_future {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::task::{Context, Poll, Waker};
+    use std::thread::{self, ThreadId};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct SignalFutureState {
+        args: Option<Vec<Variant>>,
+        waker: Option<Waker>,
+    }
+
+    /// Shared state that is moved into the connected callable.
+    struct SignalFutureResolver {
+        state: Arc<Mutex<SignalFutureState>>,
+        thread_id: ThreadId,
+    }
+
+    // `Callable::from_fn()` requires `Send + Sync`, which `SignalFutureState` is not, as it stores `Variant`s.
+    //
+    // SAFETY: the `Variant`s in the shared state are only created, accessed and dropped on the thread that created the future:
+    // - `resolve()` checks the thread ID before touching the state; on any other thread, only the `Send + Sync` ID is read.
+    // - `SignalFuture` itself is neither `Send` nor `Sync` (it holds a `Signal`), so `poll()` and `drop()` run on that thread.
+    // - `SignalFuture::drop()` clears the arguments, so if the callable is dropped last (possibly on another thread), it only
+    //   releases a state without `Variant`s. The `Waker` is `Send + Sync` on its own.
+    unsafe impl Send for SignalFutureResolver {}
+    unsafe impl Sync for SignalFutureResolver {}
+
+    impl SignalFutureResolver {
+        fn resolve(&self, args: &[&Variant]) -> Result<Variant, ()> {
+            if thread::current().id() != self.thread_id {
+                crate::godot_error!(
+                    "SignalFuture: signal emitted from a different thread than the one awaiting it"
+                );
+                return Err(());
+            }
+
+            let waker = {
+                let mut state = self.state.lock().unwrap();
+                state.args = Some(args.iter().map(|&arg| arg.clone()).collect());
+                state.waker.take()
+            };
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+
+            Ok(Variant::nil())
+        }
+    }
+
+    /// Future that resolves with the arguments of the next emission of a [`Signal`].
+    ///
+    /// Created by [`Signal::to_future()`].
+    pub struct SignalFuture {
+        state: Arc<Mutex<SignalFutureState>>,
+        signal: Signal,
+        callable: Callable,
+    }
+
+    impl SignalFuture {
+        pub(super) fn new(signal: Signal) -> Self {
+            let state = Arc::new(Mutex::new(SignalFutureState::default()));
+            let resolver = SignalFutureResolver {
+                state: state.clone(),
+                thread_id: thread::current().id(),
+            };
+
+            let callable =
+                Callable::from_fn("SignalFuture::resolve", move |args| resolver.resolve(args));
+
+            signal.connect_flags(callable.clone(), ConnectFlags::ONE_SHOT);
+
+            Self {
+                state,
+                signal,
+                callable,
+            }
+        }
+    }
+
+    impl Future for SignalFuture {
+        type Output = Vec<Variant>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let mut state = self.state.lock().unwrap();
+
+            match state.args.take() {
+                Some(args) => Poll::Ready(args),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    impl Drop for SignalFuture {
+        fn drop(&mut self) {
+            // Don't keep emitted arguments alive beyond the future, see the safety comment on `SignalFutureResolver`.
+            self.state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .args = None;
+
+            // The object may have been freed in the meantime; it must be looked up by ID before its connections are queried.
+            let is_object_alive = self
+                .signal
+                .object_id()
+                .map_or(false, |id| Gd::<Object>::try_from_instance_id(id).is_ok());
+
+            // One-shot connections are removed on emission; only disconnect if the signal has not fired yet.
+            if is_object_alive && self.signal.is_connected(self.callable.clone()) {
+                self.signal.disconnect(self.callable.clone());
+            }
+        }
+    }
+}
@@ -5,13 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::{convert::Infallible, ffi::c_char, fmt, str::FromStr};
+use std::{cmp::Ordering, convert::Infallible, ffi::c_char, fmt, str::FromStr};
 
 use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::inner;
+use crate::builtin::{inner, PackedStringArray, Variant};
 
 use super::string_chars::validate_unicode_scalar_sequence;
 use super::{NodePath, StringName};
@@ -46,6 +46,15 @@ use super::{NodePath, StringName};
 /// * you have a large number of method calls per string instance (which are more expensive due to indirectly calling into Godot)
 /// * you need UTF-8 encoding (`GString`'s encoding is platform-dependent and unspecified)
 ///
+/// # Conversion to Rust strings
+///
+/// Godot stores strings as UTF-32, so a `GString` cannot be borrowed as `&str`. Converting to `String` (via `String::from()`
+/// or `to_string()`) always allocates and re-encodes the content as UTF-8.
+///
+/// To avoid allocations, for example in hot logging paths:
+/// * Format the `GString` directly (e.g. `godot_print!("{gstring}")`); its `Display` impl writes characters without intermediate allocation.
+/// * Iterate over [`chars()`][Self::chars], which borrows the UTF-32 code points directly from Godot's buffer.
+///
 /// # Other string types
 ///
 /// Godot also provides two separate string classes with slightly different semantics: [`StringName`] and [`NodePath`].
@@ -83,6 +92,57 @@ impl GString {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Formats the string by replacing placeholders with `values`, like GDScript's `String.format()`.
+    ///
+    /// `values` can be an array, whose elements replace `{0}`, `{1}`, ... placeholders, or a dictionary, whose values replace
+    /// `{key}` placeholders.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let template = GString::from("{name} has {0} points");
+    /// let named = template.format(&dict! { "name": "Alice" }.to_variant());
+    /// let formatted = named.format(&varray![42].to_variant());
+    /// assert_eq!(formatted, GString::from("Alice has 42 points"));
+    /// ```
+    pub fn format(&self, values: &Variant) -> GString {
+        self.as_inner().format(values.clone(), GString::from("{_}"))
+    }
+
+    /// Splits the string at each occurrence of `delimiter`.
+    ///
+    /// If `allow_empty` is false, empty parts (e.g. between two adjacent delimiters) are omitted.
+    /// See also [`GString::join()`] for the inverse operation.
+    pub fn split(&self, delimiter: &str, allow_empty: bool) -> PackedStringArray {
+        self.as_inner()
+            .split(GString::from(delimiter), allow_empty, 0)
+    }
+
+    /// Concatenates all `parts`, inserting `separator` between each two of them.
+    ///
+    /// Equivalent to `separator.join(parts)` in GDScript.
+    pub fn join(parts: &PackedStringArray, separator: &str) -> GString {
+        GString::from(separator).as_inner().join(parts.clone())
+    }
+
+    /// Compares with `other` lexicographically, ignoring case.
+    ///
+    /// _Godot equivalent: `nocasecmp_to`_
+    #[doc(alias = "nocasecmp_to")]
+    pub fn nocasecmp_to(&self, other: &GString) -> Ordering {
+        self.as_inner().nocasecmp_to(other.clone()).cmp(&0)
+    }
+
+    /// Compares with `other` in natural order, ignoring case.
+    ///
+    /// Natural order treats sequences of digits as numbers, so that `"file2"` comes before `"file10"`. Useful for sorting file lists.
+    ///
+    /// _Godot equivalent: `naturalnocasecmp_to`_
+    #[doc(alias = "naturalnocasecmp_to")]
+    pub fn natural_nocasecmp_to(&self, other: &GString) -> Ordering {
+        self.as_inner().naturalnocasecmp_to(other.clone()).cmp(&0)
+    }
+
     /// Gets the internal chars slice from a [`GString`].
     ///
     /// Note: This operation is *O*(*n*). Consider using [`chars_unchecked`][Self::chars_unchecked]
@@ -230,18 +290,15 @@ impl_builtin_traits! {
 
 impl fmt::Display for GString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s: String;
+        use fmt::Write as _;
 
         #[cfg(before_api = "4.1")]
-        {
-            s = self.chars_checked().iter().collect();
-        }
+        let chars = self.chars_checked();
         #[cfg(since_api = "4.1")]
-        {
-            s = self.chars().iter().collect();
-        }
+        let chars = self.chars();
 
-        f.write_str(s.as_str())
+        // Write character by character, to avoid allocating an intermediate `String`.
+        chars.iter().try_for_each(|&c| f.write_char(c))
     }
 }
 
@@ -285,6 +342,9 @@ impl From<&String> for GString {
     }
 }
 
+/// Converts to a Rust `String`, allocating a new UTF-8 buffer.
+///
+/// See [`GString`'s conversion docs](GString#conversion-to-rust-strings) for allocation-free alternatives.
 impl From<&GString> for String {
     fn from(string: &GString) -> Self {
         unsafe {
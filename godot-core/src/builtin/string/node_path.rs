@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::fmt;
+use std::{fmt, ops};
 
 use godot_ffi as sys;
 use godot_ffi::{ffi_methods, GodotFfi};
@@ -41,6 +41,45 @@ impl NodePath {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Appends `name` as one or more node names, keeping any property subnames at the end.
+    ///
+    /// `name` may itself contain `/` separators, e.g. `"Child/Grandchild"`. As with other string conversions, anything after a
+    /// null byte is ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let mut path = NodePath::from("Parent:position");
+    /// path.push("Child");
+    /// assert_eq!(path, NodePath::from("Parent/Child:position"));
+    /// ```
+    pub fn push(&mut self, name: &str) {
+        let name = name.split('\0').next().unwrap_or_default();
+        if name.is_empty() {
+            return;
+        }
+
+        let path = GString::from(&*self).to_string();
+        let (names, subnames) = match path.split_once(':') {
+            Some((names, subnames)) => (names, Some(subnames)),
+            None => (path.as_str(), None),
+        };
+
+        let mut result = if names.is_empty() || names.ends_with('/') {
+            format!("{names}{name}")
+        } else {
+            format!("{names}/{name}")
+        };
+
+        if let Some(subnames) = subnames {
+            result.push(':');
+            result.push_str(subnames);
+        }
+
+        *self = NodePath::from(result);
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerNodePath {
         inner::InnerNodePath::from_outer(self)
@@ -84,6 +123,22 @@ impl fmt::Display for NodePath {
     }
 }
 
+/// Appends node names, see [`NodePath::push()`].
+impl ops::Add<&str> for NodePath {
+    type Output = NodePath;
+
+    fn add(mut self, name: &str) -> Self::Output {
+        self.push(name);
+        self
+    }
+}
+
+impl ops::AddAssign<&str> for NodePath {
+    fn add_assign(&mut self, name: &str) {
+        self.push(name);
+    }
+}
+
 /// Uses literal syntax from GDScript: `^"node_path"`
 impl fmt::Debug for NodePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
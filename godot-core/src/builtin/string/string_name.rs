@@ -5,14 +5,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
 use std::fmt;
 
 use godot_ffi as sys;
-use sys::{ffi_methods, GodotFfi};
+use sys::{ffi_methods, Global, GodotFfi};
 
 use crate::builtin::inner;
 use crate::builtin::{GString, NodePath};
 
+// Entries are never removed, so interned names stay valid for the rest of the process.
+static INTERNED_STRING_NAMES: Global<HashMap<&'static str, StringName>> = Global::default();
+
 /// A string optimized for unique names.
 ///
 /// StringNames are immutable strings designed for representing unique names. StringName ensures that only
@@ -66,6 +70,28 @@ impl StringName {
         c_str.into()
     }
 
+    /// Returns a `StringName` for `string` from a process-wide cache.
+    ///
+    /// The first call with a given string constructs the `StringName`; subsequent calls only return a cheap clone of the cached instance.
+    /// This is useful in hot paths that repeatedly build the same names, e.g. method names passed to `Object::call()`.
+    ///
+    /// Interned names are never freed, so only use this with a bounded set of strings (typically literals).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::StringName;
+    ///
+    /// let method = StringName::intern("take_damage");
+    /// assert_eq!(method, StringName::from("take_damage"));
+    /// ```
+    pub fn intern(string: &'static str) -> Self {
+        let mut map = INTERNED_STRING_NAMES.lock();
+
+        map.entry(string)
+            .or_insert_with(|| StringName::from(string))
+            .clone()
+    }
+
     /// Returns the number of characters in the string.
     ///
     /// _Godot equivalent: `length`_
@@ -81,6 +107,28 @@ impl StringName {
         self.as_inner().is_empty()
     }
 
+    /// Returns `true` if the string starts with the given prefix.
+    ///
+    /// _Godot equivalent: `begins_with`_
+    #[doc(alias = "starts_with")]
+    pub fn begins_with(&self, prefix: &str) -> bool {
+        self.as_inner().begins_with(GString::from(prefix))
+    }
+
+    /// Returns `true` if the string ends with the given suffix.
+    ///
+    /// _Godot equivalent: `ends_with`_
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.as_inner().ends_with(GString::from(suffix))
+    }
+
+    /// Returns `true` if the string contains `what` as a substring.
+    ///
+    /// _Godot equivalent: `contains`_
+    pub fn contains(&self, what: &str) -> bool {
+        self.as_inner().contains(GString::from(what))
+    }
+
     /// Returns a 32-bit integer hash value representing the string.
     pub fn hash(&self) -> u32 {
         self.as_inner()
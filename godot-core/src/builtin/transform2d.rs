@@ -736,4 +736,17 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&transform, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_field_order() {
+        let transform = Transform2D::from_cols(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        );
+        let expected_json = "{\"a\":{\"x\":1.0,\"y\":2.0},\"b\":{\"x\":3.0,\"y\":4.0},\"origin\":{\"x\":5.0,\"y\":6.0}}";
+
+        crate::builtin::test_utils::roundtrip(&transform, expected_json);
+    }
 }
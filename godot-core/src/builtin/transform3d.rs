@@ -497,4 +497,18 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&transform, expected_json);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_field_order() {
+        let basis = Basis::from_rows(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        let transform = Transform3D::new(basis, Vector3::new(-1.0, -2.0, -3.0));
+        let expected_json = "{\"basis\":{\"rows\":[{\"x\":1.0,\"y\":2.0,\"z\":3.0},{\"x\":4.0,\"y\":5.0,\"z\":6.0},{\"x\":7.0,\"y\":8.0,\"z\":9.0}]},\"origin\":{\"x\":-1.0,\"y\":-2.0,\"z\":-3.0}}";
+
+        crate::builtin::test_utils::roundtrip(&transform, expected_json);
+    }
 }
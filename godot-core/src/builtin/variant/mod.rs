@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{GString, StringName, VariantDispatch, VariantOperator, VariantType};
+use crate::builtin::{Array, GString, StringName, VariantDispatch, VariantOperator, VariantType};
 use crate::meta::error::ConvertError;
 use crate::meta::{ArrayElement, FromGodot, ToGodot};
 use godot_ffi as sys;
@@ -20,6 +20,13 @@ mod impls;
 /// dynamic APIs. For example, if you want to call a method on an object based on a string, you will need variants to store arguments and return
 /// value.  
 ///
+/// # Serialization
+///
+/// With the `serde` feature, variants holding JSON-like values can be serialized: `null`, `bool`, `int`, `float`, `String`,
+/// `StringName`, `NodePath`, untyped `Array` and `Dictionary` (recursively). Strings, string names and node paths are all written
+/// as strings and deserialize as [`GString`]. Any other type, including typed arrays, yields a serialization error. Containers
+/// that (directly or indirectly) contain themselves cannot be serialized.
+///
 /// See also [Godot documentation for `Variant`](https://docs.godotengine.org/en/stable/classes/class_variant.html).
 // We rely on the layout of `Variant` being the same as Godot's layout in `borrow_slice` and `borrow_slice_mut`.
 #[repr(transparent)]
@@ -95,6 +102,25 @@ impl Variant {
         }
     }
 
+    /// Returns the Godot name of the type currently held by this variant, e.g. `"int"`, `"String"` or `"Vector2"`.
+    ///
+    /// This is the name that GDScript's `type_string()` returns, and is mostly useful for logging and debugging.
+    /// Like [`get_type()`][Self::get_type], a null object is reported as `"Nil"`.
+    pub fn type_name(&self) -> &'static str {
+        variant_type_name(self.get_type())
+    }
+
+    /// Checks whether the variant holds an array that can be converted to `Array<T>`.
+    ///
+    /// This compares the array's runtime element type with the Godot type of `T`, not the Rust type itself. Rust types sharing a
+    /// Godot type are therefore not distinguished: an `Array<i64>` also matches `T = i32` or `T = u8`, since all of them are `int`.
+    /// For objects, the array's class must be the same as `T`'s; arrays of a subclass or base class don't match.
+    ///
+    /// Untyped arrays are only matched by `T = Variant`.
+    pub fn is_array_of<T: ArrayElement>(&self) -> bool {
+        self.get_type() == VariantType::ARRAY && self.try_to::<Array<T>>().is_ok()
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc).
@@ -468,3 +494,221 @@ impl fmt::Debug for Variant {
         VariantDispatch::from_variant(self).fmt(f)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Godot's name for a variant type, as returned by `type_string()` in GDScript.
+fn variant_type_name(variant_type: VariantType) -> &'static str {
+    match variant_type {
+        VariantType::NIL => "Nil",
+        VariantType::BOOL => "bool",
+        VariantType::INT => "int",
+        VariantType::FLOAT => "float",
+        VariantType::STRING => "String",
+        VariantType::VECTOR2 => "Vector2",
+        VariantType::VECTOR2I => "Vector2i",
+        VariantType::RECT2 => "Rect2",
+        VariantType::RECT2I => "Rect2i",
+        VariantType::VECTOR3 => "Vector3",
+        VariantType::VECTOR3I => "Vector3i",
+        VariantType::TRANSFORM2D => "Transform2D",
+        VariantType::VECTOR4 => "Vector4",
+        VariantType::VECTOR4I => "Vector4i",
+        VariantType::PLANE => "Plane",
+        VariantType::QUATERNION => "Quaternion",
+        VariantType::AABB => "AABB",
+        VariantType::BASIS => "Basis",
+        VariantType::TRANSFORM3D => "Transform3D",
+        VariantType::PROJECTION => "Projection",
+        VariantType::COLOR => "Color",
+        VariantType::STRING_NAME => "StringName",
+        VariantType::NODE_PATH => "NodePath",
+        VariantType::RID => "RID",
+        VariantType::OBJECT => "Object",
+        VariantType::CALLABLE => "Callable",
+        VariantType::SIGNAL => "Signal",
+        VariantType::DICTIONARY => "Dictionary",
+        VariantType::ARRAY => "Array",
+        VariantType::PACKED_BYTE_ARRAY => "PackedByteArray",
+        VariantType::PACKED_INT32_ARRAY => "PackedInt32Array",
+        VariantType::PACKED_INT64_ARRAY => "PackedInt64Array",
+        VariantType::PACKED_FLOAT32_ARRAY => "PackedFloat32Array",
+        VariantType::PACKED_FLOAT64_ARRAY => "PackedFloat64Array",
+        VariantType::PACKED_STRING_ARRAY => "PackedStringArray",
+        VariantType::PACKED_VECTOR2_ARRAY => "PackedVector2Array",
+        VariantType::PACKED_VECTOR3_ARRAY => "PackedVector3Array",
+        VariantType::PACKED_COLOR_ARRAY => "PackedColorArray",
+        #[cfg(since_api = "4.3")]
+        VariantType::PACKED_VECTOR4_ARRAY => "PackedVector4Array",
+
+        // VariantType is not a real enum; unknown values can only come from newer Godot versions.
+        _ => "<unknown>",
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use crate::builtin::{Dictionary, NodePath, VariantArray};
+    use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+    use serde::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::cell::Cell;
+    use std::fmt::Formatter;
+
+    /// Maximum nesting of arrays and dictionaries during serialization.
+    ///
+    /// Godot containers have reference semantics and can contain themselves, so cycles must be detected rather than recursed into.
+    const MAX_SERIALIZE_DEPTH: usize = 256;
+
+    thread_local! {
+        static SERIALIZE_DEPTH: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Tracks one level of container nesting for the current thread; restores the depth on drop, also on error or panic.
+    struct DepthGuard;
+
+    impl DepthGuard {
+        fn enter() -> Option<Self> {
+            let depth = SERIALIZE_DEPTH.get();
+            if depth >= MAX_SERIALIZE_DEPTH {
+                return None;
+            }
+
+            SERIALIZE_DEPTH.set(depth + 1);
+            Some(DepthGuard)
+        }
+    }
+
+    impl Drop for DepthGuard {
+        fn drop(&mut self) {
+            SERIALIZE_DEPTH.set(SERIALIZE_DEPTH.get() - 1);
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Variant {
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            // Only containers can nest; guard them against self-references.
+            let _guard = match self.get_type() {
+                VariantType::DICTIONARY | VariantType::ARRAY => {
+                    Some(DepthGuard::enter().ok_or_else(|| {
+                        S::Error::custom(format!(
+                            "cannot serialize containers nested deeper than {MAX_SERIALIZE_DEPTH} levels; does an array or dictionary contain itself?"
+                        ))
+                    })?)
+                }
+                _ => None,
+            };
+
+            match self.get_type() {
+                VariantType::NIL => serializer.serialize_unit(),
+                VariantType::BOOL => serializer.serialize_bool(self.to::<bool>()),
+                VariantType::INT => serializer.serialize_i64(self.to::<i64>()),
+                VariantType::FLOAT => serializer.serialize_f64(self.to::<f64>()),
+                VariantType::STRING => self.to::<GString>().serialize(serializer),
+                VariantType::STRING_NAME => self.to::<StringName>().serialize(serializer),
+                VariantType::NODE_PATH => self.to::<NodePath>().serialize(serializer),
+                VariantType::DICTIONARY => self.to::<Dictionary>().serialize(serializer),
+                VariantType::ARRAY => match self.try_to::<VariantArray>() {
+                    Ok(array) => array.serialize(serializer),
+                    Err(_) => Err(S::Error::custom(
+                        "typed arrays stored in a Variant cannot be serialized; serialize Array<T> directly",
+                    )),
+                },
+                _ => Err(S::Error::custom(format!(
+                    "Variant of type {} cannot be serialized",
+                    self.type_name()
+                ))),
+            }
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Variant {
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct VariantVisitor;
+            impl<'de> Visitor<'de> for VariantVisitor {
+                type Value = Variant;
+
+                fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                    formatter.write_str("null, a boolean, number, string, sequence or map")
+                }
+
+                fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+                    Ok(Variant::nil())
+                }
+
+                fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+                    Ok(Variant::nil())
+                }
+
+                fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Variant::deserialize(deserializer)
+                }
+
+                fn visit_bool<E: Error>(self, value: bool) -> Result<Self::Value, E> {
+                    Ok(value.to_variant())
+                }
+
+                fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+                    Ok(value.to_variant())
+                }
+
+                fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+                    i64::try_from(value)
+                        .map(|value| value.to_variant())
+                        .map_err(|_| {
+                            E::invalid_value(
+                                Unexpected::Unsigned(value),
+                                &"an integer in i64 range",
+                            )
+                        })
+                }
+
+                fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+                    Ok(value.to_variant())
+                }
+
+                fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                    Ok(GString::from(value).to_variant())
+                }
+
+                fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    VariantArray::deserialize(SeqAccessDeserializer::new(seq))
+                        .map(|array| array.to_variant())
+                }
+
+                fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    Dictionary::deserialize(MapAccessDeserializer::new(map))
+                        .map(|dict| dict.to_variant())
+                }
+            }
+
+            deserializer.deserialize_any(VariantVisitor)
+        }
+    }
+}
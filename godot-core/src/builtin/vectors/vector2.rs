@@ -181,6 +181,7 @@ impl GlamType for RVec2 {
 #[cfg(test)]
 mod test {
     use crate::assert_eq_approx;
+    use crate::builtin::real_consts::{FRAC_PI_2, PI, TAU};
 
     use super::*;
 
@@ -193,6 +194,33 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector2::new(1.2, 5.6));
     }
 
+    #[test]
+    fn rotation() {
+        assert_eq_approx!(Vector2::RIGHT.rotated(FRAC_PI_2), Vector2::DOWN);
+        assert_eq_approx!(Vector2::DOWN.rotated(FRAC_PI_2), Vector2::LEFT);
+        assert_eq_approx!(Vector2::RIGHT.rotated(-FRAC_PI_2), Vector2::UP);
+
+        let vector = Vector2::new(1.2, 3.4);
+        assert_eq_approx!(vector.rotated(TAU), vector);
+        assert_eq_approx!(vector.rotated(PI), -vector);
+    }
+
+    #[test]
+    fn angle_wraparound() {
+        assert_eq_approx!(Vector2::RIGHT.angle(), 0.0);
+        assert_eq_approx!(Vector2::DOWN.angle(), FRAC_PI_2);
+        assert_eq_approx!(Vector2::UP.angle(), -FRAC_PI_2);
+        assert_eq_approx!(Vector2::LEFT.angle(), PI);
+
+        // Angles are always reported in the range [-PI, PI].
+        assert_eq_approx!(Vector2::from_angle(3.0 * FRAC_PI_2).angle(), -FRAC_PI_2);
+        assert_eq_approx!(Vector2::RIGHT.rotated(TAU + FRAC_PI_2).angle(), FRAC_PI_2);
+
+        let origin = Vector2::new(1.0, 1.0);
+        assert_eq_approx!(origin.angle_to_point(Vector2::new(0.0, 1.0)), PI);
+        assert_eq_approx!(origin.angle_to_point(Vector2::new(1.0, 0.0)), -FRAC_PI_2);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -201,4 +229,45 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector2::new(1.0, 2.0);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1.0, 2.0]);
+        assert_eq!(vector.to_array(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        assert_eq!(Vector2::from([1.0, 2.0]), Vector2::new(1.0, 2.0));
+        assert_eq!(Vector2::from((1.0, 2.0)), Vector2::new(1.0, 2.0));
+        assert_eq!(Vector2::from([1.0, 2.0]).to_array(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn orthogonal() {
+        // Same as Godot: (x, y) -> (y, -x). Since Y points down, this appears counter-clockwise on screen.
+        assert_eq!(Vector2::RIGHT.orthogonal(), Vector2::UP);
+        assert_eq!(Vector2::UP.orthogonal(), Vector2::LEFT);
+
+        let vector = Vector2::new(1.2, 3.4);
+        assert_eq!(vector.orthogonal().dot(vector), 0.0);
+        assert_eq!(vector.orthogonal().length(), vector.length());
+    }
+
+    #[test]
+    fn cross_orientation() {
+        // DOWN is a clockwise quarter turn from RIGHT (on screen), UP a counter-clockwise one.
+        assert_eq!(Vector2::RIGHT.cross(Vector2::DOWN), 1.0);
+        assert_eq!(Vector2::RIGHT.cross(Vector2::UP), -1.0);
+        assert_eq!(Vector2::RIGHT.cross(Vector2::LEFT), 0.0);
+
+        let a = Vector2::new(1.2, 3.4);
+        let b = Vector2::new(-5.6, 7.8);
+        assert_eq!(a.cross(b), -b.cross(a));
+        assert_eq!(a.cross(a * 2.0), 0.0);
+    }
 }
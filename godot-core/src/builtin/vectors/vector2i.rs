@@ -41,6 +41,7 @@ impl_integer_vector_consts!(Vector2i);
 impl_vector2x_consts!(Vector2i, i32);
 
 impl_vector_fns!(Vector2i, glam::IVec2, i32, (x, y));
+impl_integer_vector_fns!(Vector2i, (x, y));
 impl_vector2x_fns!(Vector2i, i32);
 
 impl Vector2i {
@@ -134,4 +135,75 @@ mod test {
         assert_eq!(Vector2i::new(15, 15).max_axis(), None);
         assert_eq!(Vector2i::new(15, 15).min_axis(), None);
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector2i::new(1, 2);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(vector.to_array(), [1, 2]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        assert_eq!(Vector2i::from([1, 2]), Vector2i::new(1, 2));
+        assert_eq!(Vector2i::from((1, 2)), Vector2i::new(1, 2));
+        assert_eq!(Vector2i::from([1, 2]).to_array(), [1, 2]);
+    }
+
+    #[test]
+    fn distance_squared_to() {
+        let origin = Vector2i::new(1, 1);
+        let a = Vector2i::new(4, 5);
+        let b = Vector2i::new(-2, -3);
+
+        assert_eq!(origin.distance_squared_to(a), 25);
+        assert_eq!(a.distance_squared_to(origin), 25);
+        assert_eq!(origin.distance_squared_to(b), 25);
+        assert_eq!(origin.distance_squared_to(origin), 0);
+
+        // Same Manhattan distance, but different Euclidean distance.
+        let straight = Vector2i::new(7, 1);
+        let diagonal = Vector2i::new(4, 4);
+        assert!(origin.distance_squared_to(diagonal) < origin.distance_squared_to(straight));
+    }
+
+    #[test]
+    fn clampi() {
+        let vector = Vector2i::new(-5, 12);
+
+        assert_eq!(vector.clampi(0, 10), Vector2i::new(0, 10));
+        assert_eq!(vector.clampi(-10, 20), vector);
+    }
+
+    #[test]
+    fn hash_and_ord_as_map_key() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let cells = [
+            Vector2i::new(2, 0),
+            Vector2i::new(0, 5),
+            Vector2i::new(0, -1),
+            Vector2i::new(2, 0), // duplicate
+        ];
+
+        let set: HashSet<Vector2i> = cells.into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Vector2i::new(0, 5)));
+
+        // Ordering is lexicographic: first by x, then by y.
+        let map: BTreeMap<Vector2i, usize> = cells.into_iter().zip(0..).collect();
+        let keys: Vec<Vector2i> = map.keys().copied().collect();
+        assert_eq!(
+            keys,
+            [
+                Vector2i::new(0, -1),
+                Vector2i::new(0, 5),
+                Vector2i::new(2, 0)
+            ]
+        );
+        assert_eq!(map[&Vector2i::new(2, 0)], 3); // later insertion wins
+    }
 }
@@ -286,6 +286,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn rotation_quarter_turn() {
+        assert_eq_approx!(
+            Vector3::FORWARD.rotated(Vector3::UP, TAU / 4.0),
+            Vector3::LEFT
+        );
+        assert_eq_approx!(
+            Vector3::FORWARD.rotated(Vector3::UP, -TAU / 4.0),
+            Vector3::RIGHT
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotation_unnormalized_axis() {
+        Vector3::FORWARD.rotated(Vector3::new(0.0, 2.0, 0.0), TAU / 4.0);
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector3::new(1.2, 3.4, 5.6);
@@ -375,4 +393,32 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(vector.to_array(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        assert_eq!(Vector3::from([1.0, 2.0, 3.0]), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from((1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from([1.0, 2.0, 3.0]).to_array(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn clampf() {
+        let vector = Vector3::new(-1.5, 0.5, 2.5);
+
+        assert_eq!(vector.clampf(0.0, 1.0), Vector3::new(0.0, 0.5, 1.0));
+        assert_eq!(
+            vector.clampf(0.0, 1.0),
+            vector.clamp(Vector3::ZERO, Vector3::ONE)
+        );
+    }
 }
@@ -44,6 +44,7 @@ impl_integer_vector_consts!(Vector3i);
 impl_vector3x_consts!(Vector3i, i32);
 
 impl_vector_fns!(Vector3i, glam::IVec3, i32, (x, y, z));
+impl_integer_vector_fns!(Vector3i, (x, y, z));
 impl_vector3x_fns!(Vector3i, i32);
 
 impl Vector3i {
@@ -146,4 +147,21 @@ mod test {
         assert_eq!(Vector3i::new(15, 15, 25).max_axis(), Some(Vector3Axis::Z));
         assert_eq!(Vector3i::new(15, 5, 15).min_axis(), Some(Vector3Axis::Y));
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector3i::new(1, 2, 3);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(vector.to_array(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        assert_eq!(Vector3i::from([1, 2, 3]), Vector3i::new(1, 2, 3));
+        assert_eq!(Vector3i::from((1, 2, 3)), Vector3i::new(1, 2, 3));
+        assert_eq!(Vector3i::from([1, 2, 3]).to_array(), [1, 2, 3]);
+    }
 }
@@ -125,4 +125,23 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(vector.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(Vector4::from([1.0, 2.0, 3.0, 4.0]), vector);
+        assert_eq!(Vector4::from((1.0, 2.0, 3.0, 4.0)), vector);
+        assert_eq!(Vector4::from(vector.to_array()), vector);
+    }
 }
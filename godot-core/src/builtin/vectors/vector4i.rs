@@ -45,6 +45,7 @@ impl_vector_consts!(Vector4i, i32);
 impl_integer_vector_consts!(Vector4i);
 
 impl_vector_fns!(Vector4i, glam::IVec4, i32, (x, y, z, w));
+impl_integer_vector_fns!(Vector4i, (x, y, z, w));
 impl_vector4x_fns!(Vector4i, i32);
 
 impl Vector4i {
@@ -173,4 +174,21 @@ mod test {
         assert_eq!(prod_refs, expected);
         assert_eq!(prod, expected);
     }
+
+    #[test]
+    fn components_iter() {
+        let vector = Vector4i::new(1, 2, 3, 4);
+        let iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(vector.to_array(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_array_tuple() {
+        assert_eq!(Vector4i::from([1, 2, 3, 4]), Vector4i::new(1, 2, 3, 4));
+        assert_eq!(Vector4i::from((1, 2, 3, 4)), Vector4i::new(1, 2, 3, 4));
+        assert_eq!(Vector4i::from([1, 2, 3, 4]).to_array(), [1, 2, 3, 4]);
+    }
 }
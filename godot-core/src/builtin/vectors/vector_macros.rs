@@ -472,6 +472,15 @@ macro_rules! impl_float_vector_fns {
                 Self::from_glam(self.to_glam().ceil())
             }
 
+            /// Returns a new vector with all components clamped between `min` and `max`.
+            ///
+            /// # Panics
+            /// If `min` > `max`, `min` is NaN, or `max` is NaN.
+            #[inline]
+            pub fn clampf(self, min: real, max: real) -> Self {
+                self.clamp(Self::splat(min), Self::splat(max))
+            }
+
             /// Performs a cubic interpolation between this vector and `b` using `pre_a` and `post_b` as handles,
             /// and returns the result at position `weight`.
             ///
@@ -612,7 +621,6 @@ macro_rules! impl_float_vector_fns {
 
             /// A new vector with each component snapped to the closest multiple of the corresponding
             /// component in `step`.
-            // TODO: also implement for integer vectors
             #[inline]
             pub fn snapped(self, step: Self) -> Self {
                 Self::new(
@@ -634,6 +642,66 @@ macro_rules! impl_float_vector_fns {
     };
 }
 
+/// Implements functions that are present only on integer vectors.
+macro_rules! impl_integer_vector_fns {
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Names of the components, with parentheses, for example `(x, y)`.
+        ($($comp:ident),*)
+    ) => {
+        impl $Vector {
+            /// Returns a new vector with all components clamped between `min` and `max`.
+            ///
+            /// # Panics
+            /// If `min` > `max`.
+            #[inline]
+            pub fn clampi(self, min: i32, max: i32) -> Self {
+                self.clamp(Self::splat(min), Self::splat(max))
+            }
+
+            /// Returns the squared distance between this vector and `to`.
+            ///
+            /// Unlike [`Self::length`], this stays in integer arithmetic, so it can be used to compare distances exactly.
+            #[inline]
+            pub fn distance_squared_to(self, to: Self) -> i32 {
+                (to - self).length_squared()
+            }
+
+            /// A new vector with each component snapped to the closest multiple of the corresponding
+            /// component in `step`.
+            ///
+            /// Like Godot, halfway cases are rounded towards positive infinity. Components with a `step` of 0
+            /// are left unchanged.
+            #[inline]
+            pub fn snapped(self, step: Self) -> Self {
+                #[inline]
+                fn snap_one(value: i32, step: i32) -> i32 {
+                    if step == 0 {
+                        return value;
+                    }
+
+                    // Computes `floor(value / step + 0.5) * step` in integer arithmetic, widened to avoid overflow.
+                    let mut numerator = 2 * value as i64 + step as i64;
+                    let mut denominator = 2 * step as i64;
+                    if denominator < 0 {
+                        numerator = -numerator;
+                        denominator = -denominator;
+                    }
+
+                    (numerator.div_euclid(denominator) * step as i64) as i32
+                }
+
+                Self::new(
+                    $(
+                        snap_one(self.$comp, step.$comp)
+                    ),*
+                )
+            }
+        }
+    };
+}
+
 /// Implements functions present on 2D vectors.
 macro_rules! impl_vector2x_fns {
     (
@@ -676,6 +744,34 @@ macro_rules! impl_vector2x_fns {
                     _ => None,
                 }
             }
+
+            /// Returns the components of this vector as an array, in `x, y` order.
+            #[inline]
+            pub const fn to_array(self) -> [$Scalar; 2] {
+                [self.x, self.y]
+            }
+        }
+
+        /// Iterates over the components of the vector, in `x, y` order.
+        impl IntoIterator for $Vector {
+            type Item = $Scalar;
+            type IntoIter = std::array::IntoIter<$Scalar, 2>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.to_array().into_iter()
+            }
+        }
+
+        impl From<[$Scalar; 2]> for $Vector {
+            fn from([x, y]: [$Scalar; 2]) -> Self {
+                Self::new(x, y)
+            }
+        }
+
+        impl From<($Scalar, $Scalar)> for $Vector {
+            fn from((x, y): ($Scalar, $Scalar)) -> Self {
+                Self::new(x, y)
+            }
         }
 
         impl $crate::builtin::SwizzleToVector for ($Scalar, $Scalar) {
@@ -749,6 +845,34 @@ macro_rules! impl_vector3x_fns {
                     _ => None,
                 }
             }
+
+            /// Returns the components of this vector as an array, in `x, y, z` order.
+            #[inline]
+            pub const fn to_array(self) -> [$Scalar; 3] {
+                [self.x, self.y, self.z]
+            }
+        }
+
+        /// Iterates over the components of the vector, in `x, y, z` order.
+        impl IntoIterator for $Vector {
+            type Item = $Scalar;
+            type IntoIter = std::array::IntoIter<$Scalar, 3>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.to_array().into_iter()
+            }
+        }
+
+        impl From<[$Scalar; 3]> for $Vector {
+            fn from([x, y, z]: [$Scalar; 3]) -> Self {
+                Self::new(x, y, z)
+            }
+        }
+
+        impl From<($Scalar, $Scalar, $Scalar)> for $Vector {
+            fn from((x, y, z): ($Scalar, $Scalar, $Scalar)) -> Self {
+                Self::new(x, y, z)
+            }
         }
 
         impl $crate::builtin::SwizzleToVector for ($Scalar, $Scalar, $Scalar) {
@@ -822,6 +946,34 @@ macro_rules! impl_vector4x_fns {
 
                 (Some(min_value) != previous).then_some(min_axis)
             }
+
+            /// Returns the components of this vector as an array, in `x, y, z, w` order.
+            #[inline]
+            pub const fn to_array(self) -> [$Scalar; 4] {
+                [self.x, self.y, self.z, self.w]
+            }
+        }
+
+        /// Iterates over the components of the vector, in `x, y, z, w` order.
+        impl IntoIterator for $Vector {
+            type Item = $Scalar;
+            type IntoIter = std::array::IntoIter<$Scalar, 4>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.to_array().into_iter()
+            }
+        }
+
+        impl From<[$Scalar; 4]> for $Vector {
+            fn from([x, y, z, w]: [$Scalar; 4]) -> Self {
+                Self::new(x, y, z, w)
+            }
+        }
+
+        impl From<($Scalar, $Scalar, $Scalar, $Scalar)> for $Vector {
+            fn from((x, y, z, w): ($Scalar, $Scalar, $Scalar, $Scalar)) -> Self {
+                Self::new(x, y, z, w)
+            }
         }
 
         impl $crate::builtin::SwizzleToVector for ($Scalar, $Scalar, $Scalar, $Scalar) {
@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::{Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+
 /// Access vector components in different order.
 ///
 /// Allows to rearrange components, as well as to create higher- or lower-order vectors.
@@ -68,6 +70,75 @@ pub trait SwizzleToVector: Sized {
     type Output;
     fn swizzle_to_vector(self) -> Self::Output;
 }
+
+/// Implements swizzle accessor methods, such as `xy()` or `zyx()`, that return a vector of type `$Target`.
+macro_rules! impl_swizzle_fns {
+    (
+        // Name of the source vector type.
+        $Vector:ty => $Target:ty;
+        // List of methods and the components they pick, for example `xz = (x, z)`.
+        $( $name:ident = ($($comp:ident),+) ),* $(,)?
+    ) => {
+        impl $Vector {
+            $(
+                #[doc = concat!("Returns a new [`", stringify!($Target), "`] with the components `(", stringify!($($comp),+), ")` of this vector.")]
+                #[doc = ""]
+                #[doc = concat!("Shorthand for `swizzle!(v => ", stringify!($($comp),+), ")`.")]
+                #[inline]
+                pub const fn $name(self) -> $Target {
+                    <$Target>::new($( self.$comp ),+)
+                }
+            )*
+        }
+    };
+}
+
+/// Implements swizzles on 2D vectors.
+macro_rules! impl_vector2x_swizzles {
+    ($Vector2:ty) => {
+        impl_swizzle_fns!($Vector2 => $Vector2;
+            yx = (y, x),
+        );
+    };
+}
+
+/// Implements swizzles on 3D vectors, returning 2D and reordered 3D vectors.
+macro_rules! impl_vector3x_swizzles {
+    ($Vector3:ty, $Vector2:ty) => {
+        impl_swizzle_fns!($Vector3 => $Vector2;
+            xy = (x, y), xz = (x, z), yx = (y, x), yz = (y, z), zx = (z, x), zy = (z, y),
+        );
+        impl_swizzle_fns!($Vector3 => $Vector3;
+            xzy = (x, z, y), yxz = (y, x, z), yzx = (y, z, x), zxy = (z, x, y), zyx = (z, y, x),
+        );
+    };
+}
+
+/// Implements swizzles on 4D vectors, returning 2D and 3D vectors.
+macro_rules! impl_vector4x_swizzles {
+    ($Vector4:ty, $Vector3:ty, $Vector2:ty) => {
+        impl_swizzle_fns!($Vector4 => $Vector2;
+            xy = (x, y), xz = (x, z), xw = (x, w), yx = (y, x), yz = (y, z), yw = (y, w),
+            zx = (z, x), zy = (z, y), zw = (z, w), wx = (w, x), wy = (w, y), wz = (w, z),
+        );
+        impl_swizzle_fns!($Vector4 => $Vector3;
+            xyz = (x, y, z), xyw = (x, y, w), xzy = (x, z, y), xzw = (x, z, w),
+            xwy = (x, w, y), xwz = (x, w, z), yxz = (y, x, z), yxw = (y, x, w),
+            yzx = (y, z, x), yzw = (y, z, w), ywx = (y, w, x), ywz = (y, w, z),
+            zxy = (z, x, y), zxw = (z, x, w), zyx = (z, y, x), zyw = (z, y, w),
+            zwx = (z, w, x), zwy = (z, w, y), wxy = (w, x, y), wxz = (w, x, z),
+            wyx = (w, y, x), wyz = (w, y, z), wzx = (w, z, x), wzy = (w, z, y),
+        );
+    };
+}
+
+impl_vector2x_swizzles!(Vector2);
+impl_vector2x_swizzles!(Vector2i);
+impl_vector3x_swizzles!(Vector3, Vector2);
+impl_vector3x_swizzles!(Vector3i, Vector2i);
+impl_vector4x_swizzles!(Vector4, Vector3, Vector2);
+impl_vector4x_swizzles!(Vector4i, Vector3i, Vector2i);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
@@ -126,4 +197,22 @@ mod test {
         assert_eq!(swizzle!(vector3i => y, x, z, y), Vector4i::new(2, 1, 3, 2));
         assert_eq!(swizzle!(vector4i => y, x, z, w), Vector4i::new(2, 1, 3, 4));
     }
+
+    #[test]
+    fn test_vector_swizzle_fns() {
+        let vector2 = Vector2::new(1.0, 2.0);
+        let vector3 = Vector3::new(1.0, 2.0, 3.0);
+        let vector4i = Vector4i::new(1, 2, 3, 4);
+
+        assert_eq_approx!(vector2.yx(), Vector2::new(2.0, 1.0));
+
+        assert_eq_approx!(vector3.xy(), Vector2::new(1.0, 2.0));
+        assert_eq_approx!(vector3.xz(), Vector2::new(1.0, 3.0));
+        assert_eq_approx!(vector3.zyx(), Vector3::new(3.0, 2.0, 1.0));
+        assert_eq_approx!(vector3.zyx(), swizzle!(vector3 => z, y, x));
+
+        assert_eq!(vector4i.xyz(), Vector3i::new(1, 2, 3));
+        assert_eq!(vector4i.wzy(), Vector3i::new(4, 3, 2));
+        assert_eq!(vector4i.wx(), Vector2i::new(4, 1));
+    }
 }
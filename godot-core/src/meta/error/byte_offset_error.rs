@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when encoding or decoding a value in a [`PackedByteArray`][crate::builtin::PackedByteArray] at an offset where it doesn't fit.
+///
+/// Returned by methods such as [`PackedByteArray::encode_u32()`][crate::builtin::PackedByteArray::encode_u32] and
+/// [`decode_u32()`][crate::builtin::PackedByteArray::decode_u32].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ByteOffsetError {
+    byte_offset: usize,
+    value_size: usize,
+    array_len: usize,
+}
+
+impl ByteOffsetError {
+    pub(crate) fn new(byte_offset: usize, value_size: usize, array_len: usize) -> Self {
+        Self {
+            byte_offset,
+            value_size,
+            array_len,
+        }
+    }
+
+    /// The offset at which the value was to be encoded or decoded.
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Number of bytes of the encoded value.
+    pub fn value_size(&self) -> usize {
+        self.value_size
+    }
+
+    /// Length of the array at the time of the access.
+    pub fn array_len(&self) -> usize {
+        self.array_len
+    }
+}
+
+impl fmt::Display for ByteOffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot access {} byte(s) at offset {} in PackedByteArray of length {}",
+            self.value_size, self.byte_offset, self.array_len
+        )
+    }
+}
+
+impl Error for ByteOffsetError {}
@@ -152,6 +152,7 @@ pub(crate) enum ErrorKind {
     FromGodot(FromGodotError),
     FromFfi(FromFfiError),
     FromVariant(FromVariantError),
+    ToVariant(ToVariantError),
     Custom(Option<Cause>),
 }
 
@@ -161,6 +162,7 @@ impl fmt::Display for ErrorKind {
             Self::FromGodot(from_godot) => write!(f, "{from_godot}"),
             Self::FromVariant(from_variant) => write!(f, "{from_variant}"),
             Self::FromFfi(from_ffi) => write!(f, "{from_ffi}"),
+            Self::ToVariant(to_variant) => write!(f, "{to_variant}"),
             Self::Custom(cause) => write!(f, "{cause:?}"),
         }
     }
@@ -173,6 +175,10 @@ pub(crate) enum FromGodotError {
         expected: ArrayTypeInfo,
         actual: ArrayTypeInfo,
     },
+    BadArrayLength {
+        expected: usize,
+        actual: usize,
+    },
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
     ZeroInstanceId,
@@ -221,6 +227,12 @@ impl fmt::Display for FromGodotError {
                     actual.class_name()
                 )
             }
+            Self::BadArrayLength { expected, actual } => {
+                write!(
+                    f,
+                    "expected array of length {expected}, got length {actual}"
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
         }
@@ -311,6 +323,41 @@ impl fmt::Display for FromVariantError {
     }
 }
 
+/// Conversion failed during a [`ToGodot::try_to_variant()`](crate::meta::ToGodot::try_to_variant) call.
+#[derive(Eq, PartialEq, Debug)]
+pub(crate) enum ToVariantError {
+    /// `u64` value beyond `i64::MAX`.
+    U64OutOfRange { value: u64 },
+
+    /// Path that is not valid UTF-8, and thus not representable as `GString` without loss.
+    NonUtf8Path { path: std::path::PathBuf },
+}
+
+impl ToVariantError {
+    pub fn into_error(self) -> ConvertError {
+        // The value itself is not stored, as it cannot be represented as a Variant in the first place.
+        ConvertError {
+            kind: ErrorKind::ToVariant(self),
+            value: None,
+        }
+    }
+}
+
+impl fmt::Display for ToVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U64OutOfRange { value } => write!(
+                f,
+                "u64 value {value} is not representable inside Variant, which can only store i64 integers"
+            ),
+            Self::NonUtf8Path { path } => write!(
+                f,
+                "path {path:?} is not valid UTF-8 and cannot be represented as GString without loss"
+            ),
+        }
+    }
+}
+
 fn __ensure_send_sync() {
     fn check<T: Send + Sync>() {}
     check::<ErasedConvertError>();
@@ -40,6 +40,16 @@ impl Error for IoError {
 }
 
 impl IoError {
+    /// If this error occurred while loading a resource, returns the reason why loading failed.
+    ///
+    /// Returns `None` for errors unrelated to loading, e.g. from saving.
+    pub fn load_error_kind(&self) -> Option<&LoadErrorKind> {
+        match &self.data {
+            ErrorData::Load(err) => Some(&err.kind),
+            _ => None,
+        }
+    }
+
     pub(crate) fn saving(error: GodotError, class: String, path: String) -> Self {
         Self {
             data: ErrorData::Save(SaverError {
@@ -50,23 +60,9 @@ impl IoError {
         }
     }
 
-    pub(crate) fn loading(class: String, path: String) -> Self {
+    pub(crate) fn loading(kind: LoadErrorKind, class: String, path: String) -> Self {
         Self {
-            data: ErrorData::Load(LoaderError {
-                kind: LoaderErrorKind::Load,
-                class,
-                path,
-            }),
-        }
-    }
-
-    pub(crate) fn loading_cast(class: String, path: String) -> Self {
-        Self {
-            data: ErrorData::Load(LoaderError {
-                kind: LoaderErrorKind::Cast,
-                class,
-                path,
-            }),
+            data: ErrorData::Load(LoaderError { kind, class, path }),
         }
     }
 
@@ -107,19 +103,30 @@ enum ErrorData {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Reason why loading a resource failed, see [`IoError::load_error_kind()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum LoadErrorKind {
+    /// There is no resource at the given path.
+    NotFound,
+
+    /// A resource exists at the given path, but Godot could not load it (e.g. because the file is corrupt or has a syntax error).
+    LoadFailed,
+
+    /// The resource was loaded, but is not of the requested class or one of its subclasses.
+    WrongType {
+        /// Dynamic class of the loaded resource.
+        actual_class: String,
+    },
+}
+
 #[derive(Debug)]
 struct LoaderError {
-    kind: LoaderErrorKind,
+    kind: LoadErrorKind,
     class: String,
     path: String,
 }
 
-#[derive(Debug)]
-enum LoaderErrorKind {
-    Load,
-    Cast,
-}
-
 impl Error for LoaderError {}
 
 impl fmt::Display for LoaderError {
@@ -128,13 +135,17 @@ impl fmt::Display for LoaderError {
         let path = &self.path;
 
         match &self.kind {
-            LoaderErrorKind::Load => write!(
+            LoadErrorKind::NotFound => write!(
+                f,
+                "can't load resource of class: '{class}' from path: '{path}'; no such resource"
+            ),
+            LoadErrorKind::LoadFailed => write!(
                 f,
-                "can't load resource of class: '{class}' from path: '{path}'"
+                "can't load resource of class: '{class}' from path: '{path}'; file exists, but could not be parsed"
             ),
-            LoaderErrorKind::Cast => write!(
+            LoadErrorKind::WrongType { actual_class } => write!(
                 f,
-                "can't cast loaded resource to class: '{class}' from path: '{path}'"
+                "can't cast loaded resource to class: '{class}' from path: '{path}'; actual class is '{actual_class}'"
             ),
         }
     }
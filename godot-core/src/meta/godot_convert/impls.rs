@@ -5,8 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::Variant;
-use crate::meta::error::{ConvertError, FromFfiError, FromVariantError};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::builtin::{
+    Aabb, Basis, Color, Dictionary, GString, NodePath, Plane, Projection, Quaternion, Rect2,
+    Rect2i, StringName, Transform2D, Transform3D, Variant, VariantArray, Vector2, Vector2i,
+    Vector3, Vector3i, Vector4, Vector4i,
+};
+use crate::meta::error::{
+    ConvertError, FromFfiError, FromGodotError, FromVariantError, ToVariantError,
+};
 use crate::meta::{
     ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType, PropertyInfo,
     ToGodot,
@@ -102,6 +114,13 @@ where
             None => Variant::nil(),
         }
     }
+
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+        match self {
+            Some(inner) => inner.try_to_variant(),
+            None => Ok(Variant::nil()),
+        }
+    }
 }
 
 impl<T: FromGodot> FromGodot for Option<T>
@@ -143,6 +162,62 @@ where
     }
 }
 
+// Option<T> for builtins without a nullable FFI representation. These go through Variant, with nil representing None.
+//
+// Primitives such as i32 cannot be covered: their FFI type is foreign to this crate, so coherence cannot rule out that godot-ffi
+// implements GodotNullableFfi for it, and an impl here would overlap with the blanket impls above.
+
+macro_rules! impl_option_via_variant {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl GodotConvert for Option<$T> {
+                type Via = Variant;
+            }
+
+            impl ToGodot for Option<$T> {
+                fn to_godot(&self) -> Self::Via {
+                    match self {
+                        Some(inner) => inner.to_variant(),
+                        None => Variant::nil(),
+                    }
+                }
+            }
+
+            impl FromGodot for Option<$T> {
+                fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                    if via.is_nil() {
+                        return Ok(None);
+                    }
+
+                    <$T>::try_from_variant(&via).map(Some)
+                }
+            }
+        )*
+    };
+}
+
+impl_option_via_variant!(
+    Aabb,
+    Rect2,
+    Rect2i,
+    Basis,
+    Transform2D,
+    Transform3D,
+    Projection,
+    Vector2,
+    Vector2i,
+    Vector3,
+    Vector3i,
+    Vector4,
+    Vector4i,
+    Quaternion,
+    Plane,
+    Color,
+    GString,
+    StringName,
+    NodePath,
+);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Scalars
 
@@ -301,12 +376,14 @@ impl ToGodot for u64 {
     }
 
     fn to_variant(&self) -> Variant {
-        // TODO panic doesn't fit the trait's infallibility too well; maybe in the future try_to_godot/try_to_variant() methods are possible.
+        self.try_to_variant()
+            .unwrap_or_else(|err| panic!("to_variant(): {err}"))
+    }
+
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
         i64::try_from(*self)
             .map(|v| v.to_variant())
-            .unwrap_or_else(|_| {
-                panic!("to_variant(): u64 value {} is not representable inside Variant, which can only store i64 integers", self)
-            })
+            .map_err(|_rust_err| ToVariantError::U64OutOfRange { value: *self }.into_error())
     }
 }
 
@@ -326,6 +403,263 @@ impl FromGodot for u64 {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// char: represented as Unicode scalar value, like GDScript's `String.unicode_at()` and `String.chr()`.
+
+impl GodotConvert for char {
+    type Via = i64;
+}
+
+impl ToGodot for char {
+    fn to_godot(&self) -> Self::Via {
+        *self as i64
+    }
+}
+
+impl FromGodot for char {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        // Fails for negative values, surrogates and values beyond the Unicode range.
+        u32::try_from(via)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| FromVariantError::BadValue.into_error(via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// u128/i128: represented as decimal strings.
+//
+// Godot integers are 64-bit, so `i64` cannot hold the full range, and `float` would silently lose precision beyond 2^53.
+// A decimal string is lossless, human-readable in the editor and in saved files, and can be parsed by GDScript if needed.
+
+macro_rules! impl_godot_int128 {
+    ($T:ty) => {
+        impl GodotConvert for $T {
+            type Via = GString;
+        }
+
+        impl ToGodot for $T {
+            fn to_godot(&self) -> Self::Via {
+                GString::from(self.to_string())
+            }
+        }
+
+        impl FromGodot for $T {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                // Fails for non-numeric strings and values outside the type's range.
+                via.to_string()
+                    .parse::<$T>()
+                    .map_err(|_rust_err| FromVariantError::BadValue.into_error(via))
+            }
+        }
+    };
+}
+
+impl_godot_int128!(u128);
+impl_godot_int128!(i128);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Duration: represented as seconds, like time values in Godot APIs (e.g. `Timer::wait_time`).
+
+impl GodotConvert for Duration {
+    type Via = f64;
+}
+
+impl ToGodot for Duration {
+    fn to_godot(&self) -> Self::Via {
+        self.as_secs_f64()
+    }
+}
+
+impl FromGodot for Duration {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        // Fails for negative, non-finite and too large values.
+        Duration::try_from_secs_f64(via)
+            .map_err(|_rust_err| FromVariantError::BadValue.into_error(via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// PathBuf: represented as the OS path string, e.g. to pass it to `load()` or `FileAccess`.
+//
+// The path is passed through as-is; no `res://` or `user://` prefix is added and separators are not normalized.
+// Non-UTF-8 paths are converted lossily by `to_godot()` (invalid sequences become U+FFFD), while `try_to_variant()` returns an error.
+// `FromGodot` cannot fail, since Godot strings are always valid Unicode.
+
+impl GodotConvert for PathBuf {
+    type Via = GString;
+}
+
+impl ToGodot for PathBuf {
+    fn to_godot(&self) -> Self::Via {
+        GString::from(self.to_string_lossy().as_ref())
+    }
+
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+        match self.to_str() {
+            Some(s) => Ok(s.to_variant()),
+            None => Err(ToVariantError::NonUtf8Path { path: self.clone() }.into_error()),
+        }
+    }
+}
+
+impl FromGodot for PathBuf {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(PathBuf::from(via.to_string()))
+    }
+}
+
+impl GodotConvert for &Path {
+    type Via = GString;
+}
+
+impl ToGodot for &Path {
+    fn to_godot(&self) -> Self::Via {
+        GString::from(self.to_string_lossy().as_ref())
+    }
+
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+        match self.to_str() {
+            Some(s) => Ok(s.to_variant()),
+            None => Err(ToVariantError::NonUtf8Path {
+                path: self.to_path_buf(),
+            }
+            .into_error()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Ranges: represented as `Vector2i(start, end)`.
+
+/// Converts to and from `Vector2i(start, end)`.
+///
+/// Only `i32` bounds are supported, because `Vector2i` components are 32-bit. An `i64` range would have to be narrowed inside the
+/// infallible [`ToGodot::to_godot()`], silently truncating or panicking for large bounds. Convert such ranges explicitly, e.g.
+/// with `i32::try_from()` on both bounds, so that out-of-range values are handled at the call site.
+impl GodotConvert for Range<i32> {
+    type Via = Vector2i;
+}
+
+impl ToGodot for Range<i32> {
+    fn to_godot(&self) -> Self::Via {
+        Vector2i::new(self.start, self.end)
+    }
+}
+
+impl FromGodot for Range<i32> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.x..via.y)
+    }
+}
+
+/// Converts to and from `Vector2i(start, end)`, with `end` being inclusive.
+///
+/// Like for [`Range<i32>`], only `i32` bounds are supported, since `Vector2i` components are 32-bit.
+impl GodotConvert for RangeInclusive<i32> {
+    type Via = Vector2i;
+}
+
+impl ToGodot for RangeInclusive<i32> {
+    fn to_godot(&self) -> Self::Via {
+        Vector2i::new(*self.start(), *self.end())
+    }
+}
+
+impl FromGodot for RangeInclusive<i32> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.x..=via.y)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Collections
+
+impl<K, V> GodotConvert for HashMap<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K, V> ToGodot for HashMap<K, V>
+where
+    K: ToGodot,
+    V: ToGodot,
+{
+    fn to_godot(&self) -> Self::Via {
+        Dictionary::from(self)
+    }
+
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+        let mut dictionary = Dictionary::new();
+        for (key, value) in self {
+            dictionary.set(key.try_to_variant()?, value.try_to_variant()?);
+        }
+
+        Ok(dictionary.to_variant())
+    }
+}
+
+impl<K, V> FromGodot for HashMap<K, V>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from(via)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+// Heterogeneous tuples are represented as untyped arrays, with one element per tuple field.
+macro_rules! impl_tuple_convert {
+    ($len:literal; $($T:ident: $index:tt),+) => {
+        impl<$($T),+> GodotConvert for ($($T,)+) {
+            type Via = VariantArray;
+        }
+
+        impl<$($T: ToGodot),+> ToGodot for ($($T,)+) {
+            fn to_godot(&self) -> Self::Via {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(self.$index.to_variant());
+                )+
+                array
+            }
+
+            fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(self.$index.try_to_variant()?);
+                )+
+                Ok(array.to_variant())
+            }
+        }
+
+        impl<$($T: FromGodot),+> FromGodot for ($($T,)+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let actual = via.len();
+                if actual != $len {
+                    let error = FromGodotError::BadArrayLength { expected: $len, actual };
+                    return Err(error.into_error(via));
+                }
+
+                Ok(($(
+                    via.at($index).try_to::<$T>()?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple_convert!(2; A: 0, B: 1);
+impl_tuple_convert!(3; A: 0, B: 1, C: 2);
+impl_tuple_convert!(4; A: 0, B: 1, C: 2, D: 3);
+impl_tuple_convert!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_tuple_convert!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_tuple_convert!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_tuple_convert!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
@@ -51,6 +51,15 @@ pub trait ToGodot: Sized + GodotConvert {
     fn to_variant(&self) -> Variant {
         self.to_godot().to_ffi().ffi_to_variant()
     }
+
+    /// Converts this type to a [Variant], returning `Err` if the value cannot be represented.
+    ///
+    /// For most types, this cannot fail and is equivalent to `Ok(self.to_variant())`. Types with a larger domain than Godot
+    /// can store (such as `u64`) override it, so that callers can handle such values instead of having [`to_variant()`][Self::to_variant]
+    /// panic.
+    fn try_to_variant(&self) -> Result<Variant, ConvertError> {
+        Ok(self.to_variant())
+    }
 }
 
 /// Defines the canonical conversion from Godot for a type.
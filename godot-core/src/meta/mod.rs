@@ -27,6 +27,7 @@ pub(crate) use traits::{GodotFfiVariant, GodotNullableFfi};
 use crate::builtin::*;
 use crate::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
 use crate::registry::method::MethodParamOrReturnInfo;
+use crate::registry::property::export_info_functions::ExportRange;
 use crate::registry::property::{Export, PropertyHintInfo, Var};
 use godot_ffi as sys;
 
@@ -92,6 +93,24 @@ impl PropertyInfo {
             .with_hint_info(T::default_export_info())
     }
 
+    /// Create a new `PropertyInfo` representing an exported property named `property_name` with type `T`, limited to a range.
+    ///
+    /// This is equivalent to `@export_range(min, max, step)` in GDScript. For additional flags such as `or_greater` or a suffix,
+    /// use [`ExportRange`] together with [`with_hint_info()`](Self::with_hint_info).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use godot::meta::PropertyInfo;
+    ///
+    /// // Hint string: "0,10,0.1"
+    /// let property = PropertyInfo::new_range::<f64>("my_range_property", 0.0, 10.0, 0.1);
+    /// ```
+    pub fn new_range<T: Export>(property_name: &str, min: f64, max: f64, step: f64) -> Self {
+        Self::new_export::<T>(property_name)
+            .with_hint_info(ExportRange::new(min, max).step(step).into_hint_info())
+    }
+
     /// Change the `hint` and `hint_string` to be the given `hint_info`.
     ///
     /// See [`export_info_functions`](crate::registry::property::export_info_functions) for functions that return appropriate `PropertyHintInfo`s for
@@ -99,25 +118,14 @@ impl PropertyInfo {
     ///
     /// # Examples
     ///
-    /// Creating an `@export_range` property.
+    /// Creating an `@export_range` property with additional flags.
     ///
-    // TODO: Make this nicer to use.
     /// ```no_run
-    /// use godot::register::property::export_info_functions;
+    /// use godot::register::property::export_info_functions::ExportRange;
     /// use godot::meta::PropertyInfo;
     ///
     /// let property = PropertyInfo::new_export::<f64>("my_range_property")
-    ///     .with_hint_info(export_info_functions::export_range(
-    ///         0.0,
-    ///         10.0,
-    ///         Some(0.1),
-    ///         false,
-    ///         false,
-    ///         false,
-    ///         false,
-    ///         false,
-    ///         false,
-    ///     ));
+    ///     .with_hint_info(ExportRange::new(0.0, 10.0).step(0.1).or_greater().suffix("m").into_hint_info());
     /// ```
     pub fn with_hint_info(self, hint_info: PropertyHintInfo) -> Self {
         let PropertyHintInfo { hint, hint_string } = hint_info;
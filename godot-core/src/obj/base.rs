@@ -97,6 +97,13 @@ impl<T: GodotClass> Base<T> {
         (*self.obj).clone()
     }
 
+    /// Returns a [`Gd`] referencing the same object, or `None` if that object has already been destroyed.
+    ///
+    /// Unlike [`to_gd()`](Self::to_gd), this does not panic on a dead base, which lets defensive code detect that situation.
+    pub fn try_to_gd(&self) -> Option<Gd<T>> {
+        self.obj.is_instance_valid().then(|| self.to_gd())
+    }
+
     // Currently only used in outbound virtual calls (for scripts); search for: base_field(self).obj_sys().
     #[doc(hidden)]
     pub fn obj_sys(&self) -> sys::GDExtensionObjectPtr {
@@ -18,7 +18,8 @@ use crate::meta::error::{ConvertError, FromFfiError};
 use crate::meta::{ArrayElement, CallContext, FromGodot, GodotConvert, GodotType, ToGodot};
 use crate::obj::raw::RawGd;
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    bounds, cap, BorrowError, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass,
+    Inherits, InstanceId,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, PropertyHintInfo, TypeStringHint, Var};
@@ -181,14 +182,29 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Fallible version of [`bind()`][Self::bind], returning an error instead of panicking if the instance is already bound.
+    ///
+    /// This is useful in re-entrant code paths (e.g. signal handlers), where a borrow conflict is expected and can be handled
+    /// gracefully. The error tells whether the conflicting borrow is exclusive or shared.
+    pub fn try_bind(&self) -> Result<GdRef<T>, BorrowError> {
+        self.raw.try_bind()
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut], returning an error instead of panicking if the instance is already bound.
+    ///
+    /// See [`try_bind()`][Self::try_bind] for details.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, BorrowError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
 impl<T: GodotClass> Gd<T> {
     /// Looks up the given instance ID and returns the associated object, if possible.
     ///
-    /// If no such instance ID is registered, or if the dynamic type of the object behind that instance ID
-    /// is not compatible with `T`, then `None` is returned.
+    /// If no such instance ID is registered (e.g. because the object has been freed), or if the dynamic type of the object
+    /// behind that instance ID is not compatible with `T`, then an error is returned.
     pub fn try_from_instance_id(instance_id: InstanceId) -> Result<Self, ConvertError> {
         let ptr = classes::object_ptr_from_id(instance_id);
 
@@ -267,6 +283,14 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Returns whether `self` and `other` point to the same object, regardless of their static types.
+    ///
+    /// Unlike `==`, this allows comparing e.g. a `Gd<Node>` with a `Gd<Node2D>`. Identity is determined by instance ID.
+    /// Since instance IDs are never reused, this method is safe to call on dead objects and never panics.
+    pub fn eq_instance<U: GodotClass>(&self, other: &Gd<U>) -> bool {
+        self.instance_id_unchecked() == other.instance_id_unchecked()
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -416,8 +440,10 @@ impl<T: GodotClass> Gd<T> {
         T: cap::GodotDefault,
     {
         unsafe {
+            // Null if #[class(try_init)] fails; the error has already been logged.
             let object_ptr = callbacks::create::<T>(std::ptr::null_mut());
-            Gd::from_obj_sys(object_ptr)
+            Gd::from_obj_sys_or_none(object_ptr)
+                .unwrap_or_else(|_| panic!("failed to instantiate class {}", T::class_name()))
         }
     }
 
@@ -594,6 +620,38 @@ where
         // TODO: this might leak associated data in Gd<T>, e.g. ClassName.
         std::mem::forget(self);
     }
+
+    /// **Downcast:** try to convert into a smart pointer to a derived class, destroying the object on failure.
+    ///
+    /// Like [`try_cast()`][Self::try_cast], but instead of handing back the original pointer on failure, the object is freed and `None`
+    /// is returned. This avoids leaks in fallible chains, where the `Err` value would otherwise be dropped without calling `free()`.
+    ///
+    /// # Memory semantics
+    /// - On success, ownership moves to the returned `Gd<Derived>`; nothing is freed.
+    /// - On failure, the object is destroyed as if [`free()`][Self::free] was called. All other `Gd` pointers to it become invalid.
+    /// - If `T` is `Object` and the instance is dynamically reference-counted, it is not freed but only dropped, which decrements its
+    ///   reference count. For statically reference-counted types, `try_cast().ok()` has the same effect.
+    ///
+    /// # Panics
+    /// If the cast fails and the object cannot be freed, under the same conditions as [`free()`][Self::free].
+    pub fn cast_or_free<Derived>(self) -> Option<Gd<Derived>>
+    where
+        Derived: GodotClass + Inherits<T>,
+    {
+        let original = match self.try_cast::<Derived>() {
+            Ok(derived) => return Some(derived),
+            Err(original) => original,
+        };
+
+        // Runtime check in case of T=Object, no-op otherwise.
+        let ref_counted =
+            <<T as Bounds>::DynMemory as bounds::DynMemory>::is_ref_counted(&original.raw);
+        if ref_counted != Some(true) {
+            original.free();
+        }
+
+        None
+    }
 }
 
 /// _The methods in this impl block are only available for objects `T` that are reference-counted,
@@ -821,3 +879,62 @@ impl<T: GodotClass> std::panic::RefUnwindSafe for Gd<T> {}
 #[deprecated = "Removed; see `Gd::try_to_unique()`"]
 #[doc(hidden)] // No longer advertise in API docs.
 pub type NotUniqueError = ();
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use crate::builtin::GString;
+    use crate::classes::Resource;
+    use crate::tools::try_load;
+    use serde::{de, ser};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a resource as its `resource_path`, e.g. `"res://items/sword.tres"`.
+    ///
+    /// Resources without a path of their own -- created at runtime, or embedded as sub-resources in another file (`"...::id"`) --
+    /// cannot be serialized and yield an error.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<T> Serialize for Gd<T>
+    where
+        T: Inherits<Resource>,
+    {
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let path = self.upcast_ref::<Resource>().get_path().to_string();
+            if path.is_empty() || path.contains("::") {
+                return Err(ser::Error::custom(format!(
+                    "{self:?} cannot be serialized: only resources saved to their own file have a loadable path, got {path:?}"
+                )));
+            }
+
+            serializer.serialize_str(&path)
+        }
+    }
+
+    /// Deserializes a resource path and loads it with [`try_load()`][crate::tools::try_load].
+    ///
+    /// As with any resource loading, an already loaded resource is returned from Godot's cache instead of being loaded again.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, T> Deserialize<'de> for Gd<T>
+    where
+        T: Inherits<Resource>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let path = GString::deserialize(deserializer)?;
+            try_load::<T>(path.clone()).map_err(|err| {
+                de::Error::custom(format!("failed to load resource from {path:?}: {err}"))
+            })
+        }
+    }
+}
@@ -13,6 +13,7 @@ use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
 
 use godot_ffi::out;
 
+use std::fmt;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
@@ -87,6 +88,41 @@ impl<T: GodotClass> Drop for GdMut<'_, T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+///
+/// Describes the kind of borrow that prevented the new guard from being handed out.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BorrowError {
+    /// The instance is currently bound exclusively, e.g. through a [`GdMut`] guard or a `&mut self` method.
+    AlreadyBoundMut,
+
+    /// The instance is currently bound shared, e.g. through a [`GdRef`] guard or a `&self` method.
+    AlreadyBoundShared,
+}
+
+impl BorrowError {
+    pub(crate) fn from_mut_bound(is_mutably_bound: bool) -> Self {
+        if is_mutably_bound {
+            Self::AlreadyBoundMut
+        } else {
+            Self::AlreadyBoundShared
+        }
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyBoundMut => f.write_str("instance is already bound mutably"),
+            Self::AlreadyBoundShared => f.write_str("instance is already bound shared"),
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 macro_rules! make_base_ref {
     ($ident:ident, $bound:ident, $doc_type:ident, $doc_path:path, $object_name:literal) => {
         /// Shared reference guard for a [`Base`](crate::obj::Base) pointer.
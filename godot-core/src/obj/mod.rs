@@ -23,7 +23,7 @@ pub(crate) mod rtti;
 
 pub use base::*;
 pub use gd::*;
-pub use guards::{BaseMut, BaseRef, GdMut, GdRef};
+pub use guards::{BaseMut, BaseRef, BorrowError, GdMut, GdRef};
 pub use instance_id::*;
 pub use onready::*;
 pub use raw::*;
@@ -5,7 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::NodePath;
+use crate::classes::{Node, Object};
 use crate::meta::GodotConvert;
+use crate::obj::{Gd, GodotClass, Inherits};
 use crate::registry::property::{PropertyHintInfo, Var};
 use std::mem;
 
@@ -17,15 +20,18 @@ use std::mem;
 ///
 /// `OnReady<T>` should always be used as a field. There are two modes to use it:
 ///
-/// 1. **Automatic mode, using [`new()`](Self::new).**<br>
-///    Before `ready()` is called, all `OnReady` fields constructed with `new()` are automatically initialized, in the order of
+/// 1. **Automatic mode, using [`new()`](Self::new), [`from_base_fn()`](Self::from_base_fn), [`node()`](OnReady::node) or
+///    [`try_node()`](OnReady::try_node).**<br>
+///    Before `ready()` is called, all `OnReady` fields constructed with these are automatically initialized, in the order of
 ///    declaration. This means that you can safely access them in `ready()`.<br><br>
 /// 2. **Manual mode, using [`manual()`](Self::manual).**<br>
 ///    These fields are left uninitialized until you call [`init()`][Self::init] on them. This is useful if you need more complex
 ///    initialization scenarios than a closure allows. If you forget initialization, a panic will occur on first access.
 ///
 /// Conceptually, `OnReady<T>` is very close to [once_cell's `Lazy<T>`][lazy], with additional hooks into the Godot lifecycle.
-/// The absence of methods to check initialization state is deliberate: you don't need them if you follow the above two patterns.
+/// If you follow the above two patterns, you don't need to check the initialization state. For the rare code paths that may run
+/// before `ready()` (e.g. methods called during unusual initialization orders), [`try_get()`][Self::try_get] and
+/// [`try_get_mut()`][Self::try_get_mut] return `None` instead of panicking.
 /// This container is not designed as a general late-initialization solution, but tailored to the `ready()` semantics of Godot.
 ///
 /// `OnReady<T>` cannot be used with `#[export]` fields, because `ready()` is typically not called in the editor (unless `#[class(tool)]`
@@ -85,7 +91,34 @@ impl<T> OnReady<T> {
     {
         Self {
             state: InitState::AutoPrepared {
-                initializer: Box::new(init_fn),
+                initializer: Box::new(move |_base: Option<&Gd<Object>>| init_fn()),
+            },
+        }
+    }
+
+    /// Schedule automatic initialization before `ready()`, with access to the base node.
+    ///
+    /// Like [`new()`](Self::new), but the closure receives the `Base<T>` object of the enclosing class, upcast to `Node`.
+    /// This is useful to look up other nodes in the scene tree.
+    ///
+    /// # Panics
+    /// During auto-initialization, if the enclosing class has no `Base<T>` field, or its base class does not inherit `Node`.
+    pub fn from_base_fn<F>(init_fn: F) -> Self
+    where
+        F: FnOnce(&Gd<Node>) -> T + 'static,
+    {
+        Self {
+            state: InitState::AutoPrepared {
+                initializer: Box::new(move |base: Option<&Gd<Object>>| {
+                    let base = base.expect(
+                        "OnReady::from_base_fn() requires the enclosing class to have a Base<T> field",
+                    );
+                    let node = base.clone().try_cast::<Node>().unwrap_or_else(|obj| {
+                        panic!("OnReady::from_base_fn() requires the base to inherit Node, but got {obj:?}")
+                    });
+
+                    init_fn(&node)
+                }),
             },
         }
     }
@@ -122,11 +155,33 @@ impl<T> OnReady<T> {
         };
     }
 
+    /// Returns a shared reference to the value, or `None` if it is not yet initialized.
+    ///
+    /// Unlike `Deref`, this never panics.
+    pub fn try_get(&self) -> Option<&T> {
+        match &self.state {
+            InitState::Initialized { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns an exclusive reference to the value, or `None` if it is not yet initialized.
+    ///
+    /// Unlike `DerefMut`, this never panics.
+    pub fn try_get_mut(&mut self) -> Option<&mut T> {
+        match &mut self.state {
+            InitState::Initialized { value } => Some(value),
+            _ => None,
+        }
+    }
+
     /// Runs initialization.
     ///
+    /// `base` is the base object of the enclosing class, if it has a `Base<T>` field.
+    ///
     /// # Panics
     /// If the value is already initialized.
-    pub(crate) fn init_auto(&mut self) {
+    pub(crate) fn init_auto(&mut self, base: Option<&Gd<Object>>) {
         // Two branches needed, because mem::replace() could accidentally overwrite an already initialized value.
         match &self.state {
             InitState::ManualUninitialized => return, // skipped
@@ -147,11 +202,41 @@ impl<T> OnReady<T> {
         };
 
         self.state = InitState::Initialized {
-            value: initializer(),
+            value: initializer(base),
         };
     }
 }
 
+impl<T> OnReady<Gd<T>>
+where
+    T: Inherits<Node>,
+{
+    /// Schedule automatic initialization with the node at `path`, relative to the base node.
+    ///
+    /// This is what `#[init(node = "path")]` expands to.
+    ///
+    /// # Panics
+    /// During auto-initialization, if the node does not exist or cannot be cast to `T`. See also [`OnReady::try_node()`].
+    pub fn node(path: impl Into<NodePath>) -> Self {
+        let path = path.into();
+        Self::from_base_fn(move |base| base.get_node_as::<T>(path))
+    }
+}
+
+impl<T> OnReady<Option<Gd<T>>>
+where
+    T: Inherits<Node>,
+{
+    /// Schedule automatic initialization with the node at `path`, or `None` if it is absent.
+    ///
+    /// This is what `#[init(try_node = "path")]` expands to. Unlike [`OnReady::node()`], a missing node (or one that cannot be cast
+    /// to `T`) does not panic, which allows optional children.
+    pub fn try_node(path: impl Into<NodePath>) -> Self {
+        let path = path.into();
+        Self::from_base_fn(move |base| base.try_get_node_as::<T>(path))
+    }
+}
+
 // Panicking Deref is not best practice according to Rust, but constant get() calls are significantly less ergonomic and make it harder to
 // migrate between T and LateInit<T>, because all the accesses need to change.
 impl<T> std::ops::Deref for OnReady<T> {
@@ -216,7 +301,11 @@ impl<T: Var> Var for OnReady<T> {
 
 enum InitState<T> {
     ManualUninitialized,
-    AutoPrepared { initializer: Box<dyn FnOnce() -> T> },
+    AutoPrepared {
+        initializer: Box<dyn FnOnce(Option<&Gd<Object>>) -> T>,
+    },
     AutoInitializing, // needed because state cannot be empty
-    Initialized { value: T },
+    Initialized {
+        value: T,
+    },
 }
@@ -17,7 +17,9 @@ use crate::meta::{
 };
 use crate::obj::bounds::DynMemory as _;
 use crate::obj::rtti::ObjectRtti;
-use crate::obj::{bounds, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId};
+use crate::obj::{
+    bounds, BorrowError, Bounds, GdDerefTarget, GdMut, GdRef, GodotClass, InstanceId,
+};
 use crate::storage::{InstanceStorage, Storage};
 use crate::{classes, global, out};
 
@@ -380,6 +382,18 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`Self::bind()`], but returns an error instead of panicking if the instance is already bound.
+    pub(crate) fn try_bind(&self) -> Result<GdRef<T>, BorrowError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Like [`Self::bind_mut()`], but returns an error instead of panicking if the instance is already bound.
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<T>, BorrowError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -6,11 +6,12 @@
  */
 
 use crate::builder::ClassBuilder;
-use crate::builtin::GString;
+use crate::builtin::{GString, StringName, Variant};
 use crate::init::InitLevel;
 use crate::meta::ClassName;
 use crate::obj::{bounds, Base, BaseMut, BaseRef, Bounds, Gd};
 use crate::storage::Storage;
+use crate::{classes, global};
 
 use godot_ffi as sys;
 
@@ -397,6 +398,47 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
 
         BaseMut::new(base_gd, guard)
     }
+
+    /// Emits a signal on this object, routed through [`base_mut()`](WithBaseField::base_mut()).
+    ///
+    /// Shorthand for `self.base_mut().emit_signal(signal, varargs)`. Since the user instance stays accessible during the emission,
+    /// connected handlers may call back into this object.
+    ///
+    /// # Borrow semantics
+    /// For the duration of the emission, the `&mut self` borrow held by the caller (e.g. through `bind_mut()` or a `#[func]`) is released
+    /// towards Godot: handlers can invoke `#[func]` methods on this object, or even `bind()`/`bind_mut()` its `Gd` pointer, without a
+    /// double-borrow panic. Emitting through `self.to_gd().emit_signal(...)` instead keeps the borrow active, so such a re-entrant handler
+    /// panics. Once this method returns, the borrow is re-acquired; `self` may then observe changes made by handlers.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::prelude::*;
+    ///
+    /// #[derive(GodotClass)]
+    /// #[class(init, base = Node)]
+    /// struct Player {
+    ///     base: Base<Node>,
+    /// }
+    ///
+    /// #[godot_api]
+    /// impl Player {
+    ///     #[signal]
+    ///     fn hit(damage: i64);
+    ///
+    ///     #[func]
+    ///     fn take_damage(&mut self, damage: i64) {
+    ///         self.emit_signal_self("hit".into(), &[damage.to_variant()]);
+    ///     }
+    /// }
+    /// ```
+    fn emit_signal_self(&mut self, signal: StringName, varargs: &[Variant]) -> global::Error
+    where
+        Self::Base: Inherits<classes::Object>,
+    {
+        self.base_mut()
+            .upcast_mut::<classes::Object>()
+            .emit_signal(signal, varargs)
+    }
 }
 
 /// Extension trait for all reference-counted classes.
@@ -494,6 +536,12 @@ pub mod cap {
                 "__godot_user_init() called on engine class; must be overridden for user classes"
             )
         }
+
+        /// Only provided for user classes. Overridden by `#[class(try_init)]`, infallible otherwise.
+        #[doc(hidden)]
+        fn __godot_user_try_init(base: Base<Self::Base>) -> Result<Self, String> {
+            Ok(Self::__godot_user_init(base))
+        }
     }
 
     // TODO Evaluate whether we want this public or not
@@ -15,6 +15,7 @@ pub use sys::out;
 #[cfg(feature = "trace")]
 pub use crate::meta::trace;
 
+use crate::builtin::Variant;
 use crate::global::godot_error;
 use crate::meta::error::CallError;
 use crate::meta::CallContext;
@@ -115,7 +116,15 @@ pub struct ClassConfig {
 // Capability queries and internal access
 
 pub fn auto_init<T>(l: &mut crate::obj::OnReady<T>) {
-    l.init_auto();
+    l.init_auto(None);
+}
+
+pub fn auto_init_with_base<T, B>(l: &mut crate::obj::OnReady<T>, base: &crate::obj::Gd<B>)
+where
+    B: crate::obj::Inherits<crate::classes::Object>,
+{
+    let base = base.clone().upcast::<crate::classes::Object>();
+    l.init_auto(Some(&base));
 }
 
 #[cfg(since_api = "4.3")]
@@ -126,12 +135,44 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Appends default values for trailing parameters not provided by a varcall.
+///
+/// Godot does not substitute default arguments of extension methods itself; it only validates the argument count.
+/// If too few arguments remain even with defaults (or too many are passed), the pointers are returned unchanged, so that
+/// the subsequent argument count check can report the error.
+///
+/// # Safety
+/// `args_ptr` must point to `arg_count` valid variant pointers.
+pub unsafe fn varcall_args_with_defaults(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    param_count: usize,
+    default_args: &[Variant],
+) -> Vec<sys::GDExtensionConstVariantPtr> {
+    let arg_count = arg_count as usize;
+
+    let mut args = Vec::with_capacity(arg_count.max(param_count));
+    if arg_count > 0 {
+        args.extend_from_slice(std::slice::from_raw_parts(args_ptr, arg_count));
+    }
+
+    let first_default = param_count - default_args.len();
+    if arg_count >= first_default {
+        for index in arg_count..param_count {
+            args.push(default_args[index - first_default].var_sys());
+        }
+    }
+
+    args
+}
+
 pub fn flush_stdout() {
     use std::io::Write;
     std::io::stdout().flush().expect("flush stdout");
 }
 
 /// Ensure `T` is an editor plugin.
+#[cfg(not(feature = "codegen-no-editor"))]
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
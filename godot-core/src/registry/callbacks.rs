@@ -19,10 +19,11 @@ use std::any::Any;
 use sys::conv::u32_to_usize;
 use sys::interface_fn;
 
+/// Returns a null pointer if user initialization fails (see `#[class(try_init)]`); the error is logged.
 pub unsafe extern "C" fn create<T: cap::GodotDefault>(
     _class_userdata: *mut std::ffi::c_void,
 ) -> sys::GDExtensionObjectPtr {
-    create_custom(T::__godot_user_init)
+    create_custom_fallible(T::__godot_user_try_init)
 }
 
 #[cfg(since_api = "4.2")]
@@ -30,24 +31,50 @@ pub unsafe extern "C" fn recreate<T: cap::GodotDefault>(
     _class_userdata: *mut std::ffi::c_void,
     object: sys::GDExtensionObjectPtr,
 ) -> sys::GDExtensionClassInstancePtr {
-    create_rust_part_for_existing_godot_part(T::__godot_user_init, object)
+    create_rust_part_for_existing_godot_part(T::__godot_user_try_init, object).unwrap_or_else(
+        |err| {
+            log_init_error::<T>(&err);
+            std::ptr::null_mut()
+        },
+    )
 }
 
 pub(crate) fn create_custom<T, F>(make_user_instance: F) -> sys::GDExtensionObjectPtr
 where
     T: GodotClass,
     F: FnOnce(Base<T::Base>) -> T,
+{
+    create_custom_fallible(move |base| Ok(make_user_instance(base)))
+}
+
+fn create_custom_fallible<T, F>(make_user_instance: F) -> sys::GDExtensionObjectPtr
+where
+    T: GodotClass,
+    F: FnOnce(Base<T::Base>) -> Result<T, String>,
 {
     let base_class_name = T::Base::class_name();
 
     let base_ptr = unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
 
-    create_rust_part_for_existing_godot_part(make_user_instance, base_ptr);
+    if let Err(err) = create_rust_part_for_existing_godot_part(make_user_instance, base_ptr) {
+        log_init_error::<T>(&err);
+
+        // SAFETY: object was just constructed and is not yet referenced anywhere else; no Rust instance is attached.
+        unsafe { interface_fn!(object_destroy)(base_ptr) };
+        return std::ptr::null_mut();
+    }
 
     // std::mem::forget(base_class_name);
     base_ptr
 }
 
+fn log_init_error<T: GodotClass>(err: &str) {
+    crate::godot_error!(
+        "failed to initialize instance of class `{}`: {err}",
+        T::class_name()
+    );
+}
+
 // with GDExt, custom object consists from two parts: Godot object and Rust object, that are
 // bound to each other. this method takes the first by pointer, creates the second with
 // supplied state and binds them together. that's used for both brand-new objects creation and
@@ -56,17 +83,17 @@ where
 fn create_rust_part_for_existing_godot_part<T, F>(
     make_user_instance: F,
     base_ptr: sys::GDExtensionObjectPtr,
-) -> sys::GDExtensionClassInstancePtr
+) -> Result<sys::GDExtensionClassInstancePtr, String>
 where
     T: GodotClass,
-    F: FnOnce(Base<T::Base>) -> T,
+    F: FnOnce(Base<T::Base>) -> Result<T, String>,
 {
     let class_name = T::class_name();
 
     //out!("create callback: {}", class_name.backing);
 
     let base = unsafe { Base::from_sys(base_ptr) };
-    let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
+    let user_instance = make_user_instance(unsafe { Base::from_base(&base) })?;
 
     let instance = InstanceStorage::<T>::construct(user_instance, base);
     let instance_ptr = instance.into_raw();
@@ -84,7 +111,7 @@ where
     }
 
     // std::mem::forget(class_name);
-    instance_ptr
+    Ok(instance_ptr)
 }
 
 pub unsafe extern "C" fn free<T: GodotClass>(
@@ -68,6 +68,12 @@ pub trait TypeStringHint {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Blanket impls for Option<T>
+//
+// These apply whenever `Option<T::Via>` has a Godot representation, i.e. its FFI type is nullable (e.g. `Option<Gd<T>>`).
+// `None` maps to Godot's null, `Some` to the inner value. The `Option<T::Via>: GodotType` bound keeps them disjoint from the impls
+// for builtins such as `Option<GString>`, which are registered as `Variant` properties instead (see `export_impls` below).
+//
+// Primitives such as `Option<i32>` are not supported, see the `Option<T>` conversions in `meta::godot_convert`.
 
 impl<T: TypeStringHint> TypeStringHint for Option<T> {
     fn type_string() -> String {
@@ -79,6 +85,7 @@ impl<T> Var for Option<T>
 where
     T: Var + FromGodot,
     Option<T>: GodotConvert<Via = Option<T::Via>>,
+    Option<T::Via>: GodotType,
 {
     fn get_property(&self) -> Self::Via {
         self.as_ref().map(Var::get_property)
@@ -96,12 +103,17 @@ where
             None => *self = None,
         }
     }
+
+    fn property_hint() -> PropertyHintInfo {
+        T::property_hint()
+    }
 }
 
 impl<T> Export for Option<T>
 where
     T: Export,
     Option<T>: Var,
+    Option<T::Via>: GodotType,
 {
     fn default_export_info() -> PropertyHintInfo {
         T::default_export_info()
@@ -180,23 +192,174 @@ pub mod export_info_functions {
         degrees: bool,
         hide_slider: bool,
     ) -> PropertyHintInfo {
-        let hint_beginning = if let Some(step) = step {
-            format!("{min},{max},{step}")
-        } else {
-            format!("{min},{max}")
-        };
-        let rest =
-            comma_separate_boolean_idents!(or_greater, or_less, exp, radians, degrees, hide_slider);
+        ExportRange {
+            min,
+            max,
+            step,
+            or_greater,
+            or_less,
+            exp,
+            radians,
+            degrees,
+            hide_slider,
+            suffix: None,
+        }
+        .into_hint_info()
+    }
 
-        let hint_string = if rest.is_empty() {
-            hint_beginning
-        } else {
-            format!("{hint_beginning},{rest}")
-        };
+    /// Builder for `@export_range` hints, as a more readable alternative to [`export_range()`].
+    ///
+    /// All flags are disabled by default; enable them with the corresponding methods.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use godot::register::property::export_info_functions::ExportRange;
+    /// use godot::meta::PropertyInfo;
+    ///
+    /// // Hint string: "0,360,0.5,or_greater,degrees,suffix:°"
+    /// let hint_info = ExportRange::new(0.0, 360.0)
+    ///     .step(0.5)
+    ///     .or_greater()
+    ///     .degrees()
+    ///     .suffix("°")
+    ///     .into_hint_info();
+    ///
+    /// let property = PropertyInfo::new_export::<f64>("angle").with_hint_info(hint_info);
+    /// ```
+    #[derive(Clone, PartialEq, Debug)]
+    pub struct ExportRange {
+        min: f64,
+        max: f64,
+        step: Option<f64>,
+        or_greater: bool,
+        or_less: bool,
+        exp: bool,
+        radians: bool,
+        degrees: bool,
+        hide_slider: bool,
+        suffix: Option<String>,
+    }
 
-        PropertyHintInfo {
-            hint: PropertyHint::RANGE,
-            hint_string: hint_string.into(),
+    impl ExportRange {
+        /// Range from `min` to `max` (inclusive), without step and with all flags disabled.
+        pub fn new(min: f64, max: f64) -> Self {
+            Self {
+                min,
+                max,
+                step: None,
+                or_greater: false,
+                or_less: false,
+                exp: false,
+                radians: false,
+                degrees: false,
+                hide_slider: false,
+                suffix: None,
+            }
+        }
+
+        /// Sets the step size of the slider.
+        pub fn step(self, step: f64) -> Self {
+            Self {
+                step: Some(step),
+                ..self
+            }
+        }
+
+        /// Allows values above `max` to be entered manually.
+        pub fn or_greater(self) -> Self {
+            Self {
+                or_greater: true,
+                ..self
+            }
+        }
+
+        /// Allows values below `min` to be entered manually.
+        pub fn or_less(self) -> Self {
+            Self {
+                or_less: true,
+                ..self
+            }
+        }
+
+        /// Makes the slider exponential, for ranges spanning several orders of magnitude.
+        pub fn exp(self) -> Self {
+            Self { exp: true, ..self }
+        }
+
+        /// Edits the value in degrees, while storing it in radians.
+        pub fn radians(self) -> Self {
+            Self {
+                radians: true,
+                ..self
+            }
+        }
+
+        /// Displays the value in degrees.
+        pub fn degrees(self) -> Self {
+            Self {
+                degrees: true,
+                ..self
+            }
+        }
+
+        /// Hides the slider, only showing the number field.
+        pub fn hide_slider(self) -> Self {
+            Self {
+                hide_slider: true,
+                ..self
+            }
+        }
+
+        /// Displays a unit suffix next to the value in the editor, e.g. `"px"`.
+        pub fn suffix(self, suffix: impl Into<String>) -> Self {
+            Self {
+                suffix: Some(suffix.into()),
+                ..self
+            }
+        }
+
+        /// Converts to the `RANGE` hint with the corresponding hint string.
+        pub fn into_hint_info(self) -> PropertyHintInfo {
+            let Self {
+                min,
+                max,
+                step,
+                or_greater,
+                or_less,
+                exp,
+                radians,
+                degrees,
+                hide_slider,
+                suffix,
+            } = self;
+
+            let mut hint_string = if let Some(step) = step {
+                format!("{min},{max},{step}")
+            } else {
+                format!("{min},{max}")
+            };
+
+            let rest = comma_separate_boolean_idents!(
+                or_greater,
+                or_less,
+                exp,
+                radians,
+                degrees,
+                hide_slider
+            );
+            if !rest.is_empty() {
+                hint_string = format!("{hint_string},{rest}");
+            }
+
+            if let Some(suffix) = suffix {
+                hint_string = format!("{hint_string},suffix:{suffix}");
+            }
+
+            PropertyHintInfo {
+                hint: PropertyHint::RANGE,
+                hint_string: hint_string.into(),
+            }
         }
     }
 
@@ -381,6 +544,17 @@ mod export_impls {
             impl_property_by_godot_convert!(@type_string_hint $Ty);
         };
 
+        ($Ty:ty, nullable) => {
+            impl_property_by_godot_convert!($Ty);
+            impl_property_by_godot_convert!(@option $Ty);
+        };
+
+        ($Ty:ty, int_range) => {
+            impl_property_by_godot_convert!(@property $Ty);
+            impl_property_by_godot_convert!(@export $Ty);
+            impl_property_by_godot_convert!(@type_string_hint_int_range $Ty);
+        };
+
         (@property $Ty:ty) => {
             impl Var for $Ty {
                 fn get_property(&self) -> Self::Via {
@@ -407,38 +581,71 @@ mod export_impls {
                     builtin_type_string::<$Ty>()
                 }
             }
-        }
+        };
+
+        // `None` is stored as nil, so the property is registered as `Variant`; the hint is still the one of the inner type.
+        (@option $Ty:ty) => {
+            impl Var for Option<$Ty> {
+                fn get_property(&self) -> Self::Via {
+                    self.to_godot()
+                }
+
+                fn set_property(&mut self, value: Self::Via) {
+                    *self = FromGodot::from_godot(value);
+                }
+
+                fn property_hint() -> PropertyHintInfo {
+                    <$Ty as Var>::property_hint()
+                }
+            }
+
+            impl Export for Option<$Ty> {
+                fn default_export_info() -> PropertyHintInfo {
+                    <$Ty as Export>::default_export_info()
+                }
+            }
+        };
+
+        // Integers narrower than Godot's i64 additionally encode their value range, so that e.g. `Array<i8>` elements cannot be
+        // set to out-of-range values in the editor.
+        (@type_string_hint_int_range $Ty:ty) => {
+            impl TypeStringHint for $Ty {
+                fn type_string() -> String {
+                    int_range_type_string(<$Ty>::MIN as i64, <$Ty>::MAX as i64)
+                }
+            }
+        };
     }
 
     // Bounding Boxes
-    impl_property_by_godot_convert!(Aabb);
-    impl_property_by_godot_convert!(Rect2);
-    impl_property_by_godot_convert!(Rect2i);
+    impl_property_by_godot_convert!(Aabb, nullable);
+    impl_property_by_godot_convert!(Rect2, nullable);
+    impl_property_by_godot_convert!(Rect2i, nullable);
 
     // Matrices
-    impl_property_by_godot_convert!(Basis);
-    impl_property_by_godot_convert!(Transform2D);
-    impl_property_by_godot_convert!(Transform3D);
-    impl_property_by_godot_convert!(Projection);
+    impl_property_by_godot_convert!(Basis, nullable);
+    impl_property_by_godot_convert!(Transform2D, nullable);
+    impl_property_by_godot_convert!(Transform3D, nullable);
+    impl_property_by_godot_convert!(Projection, nullable);
 
     // Vectors
-    impl_property_by_godot_convert!(Vector2);
-    impl_property_by_godot_convert!(Vector2i);
-    impl_property_by_godot_convert!(Vector3);
-    impl_property_by_godot_convert!(Vector3i);
-    impl_property_by_godot_convert!(Vector4);
-    impl_property_by_godot_convert!(Vector4i);
+    impl_property_by_godot_convert!(Vector2, nullable);
+    impl_property_by_godot_convert!(Vector2i, nullable);
+    impl_property_by_godot_convert!(Vector3, nullable);
+    impl_property_by_godot_convert!(Vector3i, nullable);
+    impl_property_by_godot_convert!(Vector4, nullable);
+    impl_property_by_godot_convert!(Vector4i, nullable);
 
     // Misc Math
-    impl_property_by_godot_convert!(Quaternion);
-    impl_property_by_godot_convert!(Plane);
+    impl_property_by_godot_convert!(Quaternion, nullable);
+    impl_property_by_godot_convert!(Plane, nullable);
 
     // Stringy Types
-    impl_property_by_godot_convert!(GString);
-    impl_property_by_godot_convert!(StringName);
-    impl_property_by_godot_convert!(NodePath);
+    impl_property_by_godot_convert!(GString, nullable);
+    impl_property_by_godot_convert!(StringName, nullable);
+    impl_property_by_godot_convert!(NodePath, nullable);
 
-    impl_property_by_godot_convert!(Color);
+    impl_property_by_godot_convert!(Color, nullable);
 
     // Arrays
     // We manually implement `Export`.
@@ -467,12 +674,12 @@ mod export_impls {
     // accepting one of the below values then rust will panic. In the editor this will appear as the property
     // failing to be set to a value and an error printed in the console. During runtime this will crash the
     // program and print the panic from rust stating that the property cannot store the value.
-    impl_property_by_godot_convert!(i32);
-    impl_property_by_godot_convert!(i16);
-    impl_property_by_godot_convert!(i8);
-    impl_property_by_godot_convert!(u32);
-    impl_property_by_godot_convert!(u16);
-    impl_property_by_godot_convert!(u8);
+    impl_property_by_godot_convert!(i32, int_range);
+    impl_property_by_godot_convert!(i16, int_range);
+    impl_property_by_godot_convert!(i8, int_range);
+    impl_property_by_godot_convert!(u32, int_range);
+    impl_property_by_godot_convert!(u16, int_range);
+    impl_property_by_godot_convert!(u8, int_range);
 
     // Callables and Signals are useless when exported to the editor, so we only need to make them available as
     // properties.
@@ -506,3 +713,14 @@ pub(crate) fn builtin_type_string<T: GodotType>() -> String {
         format!("{}:{}", variant_type.sys(), T::godot_type_name())
     }
 }
+
+/// Type string for an `int` element restricted to `min..=max`, in the format `"type/hint:hint_string"`.
+pub(crate) fn int_range_type_string(min: i64, max: i64) -> String {
+    use crate::obj::EngineEnum as _;
+
+    format!(
+        "{}/{}:{min},{max}",
+        crate::builtin::VariantType::INT.sys(),
+        PropertyHint::RANGE.ord()
+    )
+}
@@ -13,7 +13,7 @@ use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(feature = "experimental-threads")]
 use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
 
-use crate::obj::{Base, Gd, GodotClass, Inherits};
+use crate::obj::{Base, BorrowError, Gd, GodotClass, Inherits};
 use crate::{godot_error, out};
 
 #[derive(Copy, Clone, Debug)]
@@ -92,6 +92,12 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
+    /// Like [`get()`](Storage::get()), but returns an error instead of panicking if the instance is already bound.
+    fn try_get(&self) -> Result<RefGuard<'_, Self::Instance>, BorrowError>;
+
+    /// Like [`get_mut()`](Storage::get_mut()), but returns an error instead of panicking if the instance is already bound.
+    fn try_get_mut(&self) -> Result<MutGuard<'_, Self::Instance>, BorrowError>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
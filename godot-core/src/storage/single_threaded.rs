@@ -14,7 +14,7 @@ use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(feature = "experimental-threads")]
 use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 
-use crate::obj::{Base, GodotClass};
+use crate::obj::{Base, BorrowError, GodotClass};
 use crate::out;
 use crate::storage::{Lifecycle, Storage, StorageRefCounted};
 
@@ -85,6 +85,18 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         })
     }
 
+    fn try_get(&self) -> Result<RefGuard<'_, T>, BorrowError> {
+        self.user_instance.borrow().map_err(|_| {
+            BorrowError::from_mut_bound(self.user_instance.is_currently_mutably_bound())
+        })
+    }
+
+    fn try_get_mut(&self) -> Result<MutGuard<'_, T>, BorrowError> {
+        self.user_instance.borrow_mut().map_err(|_| {
+            BorrowError::from_mut_bound(self.user_instance.is_currently_mutably_bound())
+        })
+    }
+
     fn get_inaccessible<'a: 'b, 'b>(
         &'a self,
         value: &'b mut Self::Instance,
@@ -337,6 +337,31 @@ impl GFile {
         Ok(val)
     }
 
+    /// Reads the next line of the file as [`String`], or `None` if the end of the file is reached.
+    ///
+    /// Reading starts at the current cursor position. The line terminator (`"\n"` or `"\r\n"`) is not included in the
+    /// returned string. The last line is returned even if it isn't terminated by a newline. Returns an error if the line is
+    /// not valid UTF-8.
+    ///
+    /// To iterate over all remaining lines, use the [`BufRead`] trait method
+    /// [`lines()`](https://doc.rust-lang.org/std/io/trait.BufRead.html#method.lines), e.g. `file.by_ref().lines()`.
+    /// This method is not called `read_line()` to avoid shadowing [`BufRead::read_line()`].
+    pub fn read_string_line(&mut self) -> std::io::Result<Option<String>> {
+        let mut line = String::new();
+        if BufRead::read_line(self, &mut line)? == 0 {
+            return Ok(None);
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+
+        Ok(Some(line))
+    }
+
     /// Reads the whole file as UTF-8 [`GString`].
     ///
     /// If `skip_cr` is set to `true`, carriage return (`'\r'`) will be ignored, and only line feed (`'\n'`) indicates a new line.
@@ -776,7 +801,7 @@ impl Seek for GFile {
                 Ok(position)
             }
             SeekFrom::End(offset) => {
-                if (self.check_file_length() as i64) < offset {
+                if (self.check_file_length() as i64) + offset < 0 {
                     return Err(std::io::Error::new(
                         ErrorKind::InvalidInput,
                         "Position can't be set before the file beginning",
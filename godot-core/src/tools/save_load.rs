@@ -6,9 +6,11 @@
  */
 
 use crate::builtin::GString;
+use crate::classes::resource_saver::SaverFlags;
 use crate::classes::{Resource, ResourceLoader, ResourceSaver};
 use crate::engine::IoError;
 use crate::global::Error as GodotError;
+use crate::meta::error::LoadErrorKind;
 use crate::obj::{Gd, Inherits};
 
 /// ⚠️ Loads a resource from the filesystem located at `path`, panicking on error.
@@ -40,6 +42,7 @@ where
 /// This might cause slight delay, especially when loading scenes.
 ///
 /// This function can fail if resource can't be loaded by [`ResourceLoader`] or if the subsequent cast into `T` fails.
+/// [`IoError::load_error_kind()`] tells these cases apart.
 ///
 /// This method is a simplified version of [`ResourceLoader::load()`][crate::classes::ResourceLoader::load],
 /// which can be used for more advanced scenarios.
@@ -91,7 +94,7 @@ where
     T: Inherits<Resource>,
 {
     let path = path.into();
-    save_impl(obj, &path)
+    save_impl(obj, &path, SaverFlags::NONE)
         .unwrap_or_else(|err| panic!("failed to save resource at path '{}': {}", &path, err));
 }
 
@@ -126,7 +129,33 @@ pub fn try_save<T>(obj: Gd<T>, path: impl Into<GString>) -> Result<(), IoError>
 where
     T: Inherits<Resource>,
 {
-    save_impl(obj, &path.into())
+    save_impl(obj, &path.into(), SaverFlags::NONE)
+}
+
+/// Saves a [`Resource`]-inheriting object into the file located at `path`, using the given [`SaverFlags`].
+///
+/// Like [`try_save`], but allows to customize how the resource is written, e.g. to compress binary `.res` files or to bundle
+/// sub-resources into the same file. Flags can be combined with `|`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::resource_saver::SaverFlags;
+/// use godot::tools::try_save_with_flags;
+///
+/// let res = try_save_with_flags(Resource::new_gd(), "user://save.res", SaverFlags::COMPRESS);
+/// assert!(res.is_ok());
+/// ```
+#[inline]
+pub fn try_save_with_flags<T>(
+    obj: Gd<T>,
+    path: impl Into<GString>,
+    flags: SaverFlags,
+) -> Result<(), IoError>
+where
+    T: Inherits<Resource>,
+{
+    save_impl(obj, &path.into(), flags)
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -139,26 +168,37 @@ where
     T: Inherits<Resource>,
 {
     // TODO unclone GString
-    match ResourceLoader::singleton()
+    let Some(res) = ResourceLoader::singleton()
         .load_ex(path.clone())
         .type_hint(T::class_name().to_gstring())
         .done()
-    {
-        Some(res) => match res.try_cast::<T>() {
-            Ok(obj) => Ok(obj),
-            Err(_) => Err(IoError::loading_cast(
-                T::class_name().to_string(),
-                path.to_string(),
-            )),
-        },
-        None => Err(IoError::loading(
+    else {
+        // Only check for existence once loading failed, to keep the common path fast.
+        let kind = if ResourceLoader::singleton().exists(path.clone()) {
+            LoadErrorKind::LoadFailed
+        } else {
+            LoadErrorKind::NotFound
+        };
+
+        return Err(IoError::loading(
+            kind,
             T::class_name().to_string(),
             path.to_string(),
-        )),
-    }
+        ));
+    };
+
+    res.try_cast::<T>().map_err(|res| {
+        IoError::loading(
+            LoadErrorKind::WrongType {
+                actual_class: res.get_class().to_string(),
+            },
+            T::class_name().to_string(),
+            path.to_string(),
+        )
+    })
 }
 
-fn save_impl<T>(obj: Gd<T>, path: &GString) -> Result<(), IoError>
+fn save_impl<T>(obj: Gd<T>, path: &GString, flags: SaverFlags) -> Result<(), IoError>
 where
     T: Inherits<Resource>,
 {
@@ -166,6 +206,7 @@ where
     let res = ResourceSaver::singleton()
         .save_ex(obj.upcast())
         .path(path.clone())
+        .flags(flags)
         .done();
 
     if res == GodotError::OK {
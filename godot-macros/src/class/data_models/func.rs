@@ -19,6 +19,8 @@ pub struct FuncDefinition {
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
     pub is_script_virtual: bool,
+    /// Expressions evaluating to `Variant` default values, for the trailing parameters declared in `#[func(defaults = ...)]`.
+    pub default_args: Vec<TokenStream>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -85,8 +87,10 @@ pub fn make_method_registration(
         method_name.to_string()
     };
 
+    let default_args = &func_definition.default_args;
+
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
+    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure, default_args);
     let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
 
     // String literals II
@@ -129,7 +133,9 @@ pub fn make_method_registration(
                     &[
                         #( #param_ident_strs ),*
                     ],
-                    Vec::new()
+                    vec![
+                        #( #default_args ),*
+                    ]
                 )
             };
 
@@ -147,6 +153,58 @@ pub fn make_method_registration(
     Ok(registration)
 }
 
+/// Maps the `#[func(defaults = (param = value, ...))]` entries to `Variant` expressions, in parameter order.
+///
+/// Godot only supports defaults for trailing parameters, so the parameters with defaults must come last.
+pub fn make_default_args(
+    signature_info: &SignatureInfo,
+    defaults: Vec<(Ident, TokenStream)>,
+) -> ParseResult<Vec<TokenStream>> {
+    let mut remaining = defaults;
+    let mut default_args = Vec::new();
+
+    let params = signature_info
+        .param_idents
+        .iter()
+        .zip(signature_info.param_types.iter());
+
+    for (param_ident, param_type) in params {
+        // Parameters such as `_unused` are registered without the underscore; accept both spellings.
+        let param_str = param_ident.to_string();
+        let position = remaining.iter().position(|(name, _)| {
+            name == param_ident || name.to_string().strip_prefix('_') == Some(param_str.as_str())
+        });
+
+        match position {
+            Some(position) => {
+                let (_, value) = remaining.remove(position);
+                default_args.push(quote! {
+                    ::godot::meta::ToGodot::to_variant(&{
+                        let value: #param_type = #value;
+                        value
+                    })
+                });
+            }
+            None if !default_args.is_empty() => {
+                return bail_fn(
+                    format!("parameter `{param_ident}` must have a default value, since it follows a parameter with default value"),
+                    param_ident,
+                );
+            }
+            None => {}
+        }
+    }
+
+    if let Some((name, _)) = remaining.first() {
+        return bail_fn(
+            format!("default value for unknown parameter `{name}`"),
+            name,
+        );
+    }
+
+    Ok(default_args)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -406,9 +464,34 @@ fn make_method_flags(
 }
 
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    default_args: &[TokenStream],
+) -> TokenStream {
     let invocation = make_varcall_invocation(wrapped_method);
 
+    // Godot passes only the arguments provided by the caller; defaults must be filled in by us.
+    let invocation = if default_args.is_empty() {
+        invocation
+    } else {
+        quote! {
+            {
+                let default_args = [ #( #default_args ),* ];
+                let args = ::godot::private::varcall_args_with_defaults(
+                    args_ptr,
+                    arg_count,
+                    <Sig as ::godot::meta::VarcallSignatureTuple>::PARAM_COUNT,
+                    &default_args,
+                );
+                let args_ptr = args.as_ptr();
+                let arg_count = args.len() as sys::GDExtensionInt;
+
+                #invocation
+            }
+        }
+    };
+
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
         unsafe extern "C" fn varcall_fn(
@@ -6,8 +6,9 @@
  */
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, SignalDefinition, SignatureInfo,
+    into_signature_info, make_constant_registration, make_default_args, make_method_registration,
+    make_signal_methods, make_signal_registrations, ConstDefinition, FuncDefinition,
+    SignalDefinition, SignatureInfo,
 };
 use crate::util::{bail, require_api_version, KvParser};
 use crate::{util, ParseResult};
@@ -22,6 +23,7 @@ enum ItemAttrType {
         rename: Option<String>,
         is_virtual: bool,
         has_gd_self: bool,
+        defaults: Vec<(Ident, TokenStream)>,
     },
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
@@ -51,7 +53,8 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
-    let signal_registrations = make_signal_registrations(signals, &class_name_obj);
+    let signal_registrations = make_signal_registrations(&signals, &class_name_obj);
+    let signal_methods = make_signal_methods(&signals, &class_name);
 
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
@@ -63,6 +66,8 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     let result = quote! {
         #impl_block
 
+        #signal_methods
+
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
                 #( #method_registrations )*
@@ -128,6 +133,7 @@ fn process_godot_fns(
                 rename,
                 is_virtual,
                 has_gd_self,
+                defaults,
             } => {
                 let external_attributes = function.attributes.clone();
 
@@ -175,11 +181,14 @@ fn process_godot_fns(
                     );
                 };
 
+                let default_args = make_default_args(&signature_info, defaults)?;
+
                 func_definitions.push(FuncDefinition {
                     signature_info,
                     external_attributes,
                     rename,
                     is_script_virtual: is_virtual,
+                    default_args,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
@@ -352,8 +361,8 @@ where
                 // Safe unwrap since #[func] must be present if we got to this point
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
-                // #[func(rename = MyClass)]
-                let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
+                // #[func(rename = my_name)] or #[func(rename = "myName")]
+                let rename = parser.handle_ident_or_string("rename")?;
 
                 // #[func(virtual)]
                 let is_virtual = if let Some(span) = parser.handle_alone_with_span("virtual")? {
@@ -366,6 +375,15 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(defaults = (param = value, ...))]
+                let mut defaults = Vec::new();
+                if let Some(mut list_parser) = parser.handle_list("defaults")? {
+                    while let Some((param, value)) = list_parser.try_next_key_value() {
+                        defaults.push((param, value.expr()?));
+                    }
+                    list_parser.finish()?;
+                }
+
                 parser.finish()?;
 
                 ItemAttr {
@@ -375,6 +393,7 @@ where
                         rename,
                         is_virtual,
                         has_gd_self,
+                        defaults,
                     },
                 }
             }
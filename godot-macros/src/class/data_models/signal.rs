@@ -6,8 +6,8 @@
  */
 
 use crate::util;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 /// Holds information known from a signal's definition
 pub struct SignalDefinition {
@@ -19,7 +19,7 @@ pub struct SignalDefinition {
 }
 
 pub fn make_signal_registrations(
-    signals: Vec<SignalDefinition>,
+    signals: &[SignalDefinition],
     class_name_obj: &TokenStream,
 ) -> Vec<TokenStream> {
     let mut signal_registrations = Vec::new();
@@ -89,3 +89,75 @@ pub fn make_signal_registrations(
     }
     signal_registrations
 }
+
+/// Generates typed `emit_<signal>()` and `connect_<signal>()` methods for each signal.
+///
+/// The methods go through the base field, so they are only usable on classes implementing `WithBaseField`.
+pub fn make_signal_methods(signals: &[SignalDefinition], class_name: &Ident) -> TokenStream {
+    let mut signal_methods = Vec::new();
+
+    for signal in signals.iter() {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+        } = signal;
+
+        let mut param_names: Vec<Ident> = Vec::new();
+        let mut param_types: Vec<venial::TypeExpr> = Vec::new();
+
+        for param in signature.params.inner.iter() {
+            if let venial::FnParam::Typed(param) = &param.0 {
+                param_names.push(param.name.clone());
+                param_types.push(param.ty.clone());
+            }
+        }
+
+        let signal_cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let vis = &signature.vis_marker;
+        let signal_name_str = signature.name.to_string();
+        let emit_name = format_ident!("emit_{}", signature.name);
+        let connect_name = format_ident!("connect_{}", signature.name);
+        let emit_doc = format!("Emits the `{signal_name_str}` signal with the given arguments.");
+        let connect_doc = format!("Connects the `{signal_name_str}` signal to `callable`.");
+
+        // `for<'a>` defers checking the bound to call sites, so classes without a `Base<T>` field still compile.
+        signal_methods.push(quote! {
+            #(#signal_cfg_attrs)*
+            #[doc = #emit_doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis fn #emit_name(&mut self, #( #param_names: #param_types ),*)
+            where
+                for<'a> Self: ::godot::obj::WithBaseField,
+            {
+                use ::godot::obj::WithBaseField as _;
+
+                let args = [#( ::godot::meta::ToGodot::to_variant(&#param_names) ),*];
+                self.base_mut().emit_signal(::godot::builtin::StringName::from(#signal_name_str), &args);
+            }
+
+            #(#signal_cfg_attrs)*
+            #[doc = #connect_doc]
+            #vis fn #connect_name(&mut self, callable: ::godot::builtin::Callable) -> ::godot::global::Error
+            where
+                for<'a> Self: ::godot::obj::WithBaseField,
+            {
+                use ::godot::obj::WithBaseField as _;
+
+                self.base_mut().connect(::godot::builtin::StringName::from(#signal_name_str), callable)
+            }
+        });
+    }
+
+    if signal_methods.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        impl #class_name {
+            #( #signal_methods )*
+        }
+    }
+}
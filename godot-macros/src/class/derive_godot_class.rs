@@ -24,10 +24,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let fields = parse_fields(class, struct_cfg.init_strategy)?;
 
     let class_name = &class.name;
-    let class_name_str: String = struct_cfg
-        .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
+    let class_name_str: String = struct_cfg.rename.unwrap_or_else(|| class.name.to_string());
     let class_name_cstr = util::cstr_u8_slice(&class_name_str);
     let class_name_obj = util::class_name_obj(class_name);
 
@@ -64,7 +61,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     };
 
     let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+        make_user_class_impl(class_name, struct_cfg.is_tool, &fields);
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -81,6 +78,14 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 recreate_fn = quote! { Some(#prv::callbacks::recreate::<#class_name>) };
             }
         }
+        InitStrategy::Fallible => {
+            godot_init_impl = make_godot_try_init_impl(class_name);
+            create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
+
+            if cfg!(since_api = "4.2") {
+                recreate_fn = quote! { Some(#prv::callbacks::recreate::<#class_name>) };
+            }
+        }
         InitStrategy::UserDefined => {
             let fn_name = format_ident!("class_{}_must_have_an_init_method", class_name);
             init_expecter = quote! {
@@ -179,6 +184,8 @@ pub fn make_existence_check(ident: &Ident) -> TokenStream {
 #[derive(Copy, Clone)]
 enum InitStrategy {
     Generated,
+    /// `#[class(try_init)]`: delegates to a user-provided `try_init()` function, which may fail.
+    Fallible,
     UserDefined,
     Absent,
 }
@@ -189,7 +196,7 @@ struct ClassAttributes {
     is_tool: bool,
     is_editor_plugin: bool,
     is_hidden: bool,
-    rename: Option<Ident>,
+    rename: Option<String>,
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
@@ -220,18 +227,43 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
-fn make_user_class_impl(
-    class_name: &Ident,
-    is_tool: bool,
-    all_fields: &[Field],
-) -> (TokenStream, bool) {
+fn make_godot_try_init_impl(class_name: &Ident) -> TokenStream {
+    let class_name_str = class_name.to_string();
+
+    quote! {
+        impl ::godot::obj::cap::GodotDefault for #class_name {
+            fn __godot_user_init(base: ::godot::obj::Base<Self::Base>) -> Self {
+                Self::__godot_user_try_init(base).unwrap_or_else(|err| {
+                    panic!("failed to initialize instance of class `{}`: {err}", #class_name_str)
+                })
+            }
+
+            fn __godot_user_try_init(
+                base: ::godot::obj::Base<Self::Base>,
+            ) -> ::std::result::Result<Self, ::std::string::String> {
+                Self::try_init(base).map_err(|err| err.to_string())
+            }
+        }
+    }
+}
+
+fn make_user_class_impl(class_name: &Ident, is_tool: bool, fields: &Fields) -> (TokenStream, bool) {
+    let all_fields = &fields.all_fields;
     let onready_field_inits = all_fields
         .iter()
         .filter(|&field| field.is_onready)
         .map(|field| {
             let field = &field.name;
-            quote! {
-                ::godot::private::auto_init(&mut self.#field);
+
+            // With a base field, initializers such as OnReady::node() can look up nodes relative to the base.
+            if let Some(Field { name: base, .. }) = &fields.base_field {
+                quote! {
+                    ::godot::private::auto_init_with_base(&mut self.#field, &self.#base.to_gd());
+                }
+            } else {
+                quote! {
+                    ::godot::private::auto_init(&mut self.#field);
+                }
             }
         });
 
@@ -283,7 +315,7 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut is_tool = false;
     let mut is_editor_plugin = false;
     let mut is_hidden = false;
-    let mut rename: Option<Ident> = None;
+    let mut rename: Option<String> = None;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -292,8 +324,17 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             base_ty = base;
         }
 
-        // #[class(rename = NewName)]
-        rename = parser.handle_ident("rename")?;
+        // #[class(rename = NewName)] or #[class(rename = "NewName")]
+        rename = parser.handle_ident_or_string("rename")?;
+        if let Some(name) = &rename {
+            // Godot's ClassDB only accepts identifiers; anything else would fail at registration time.
+            if !is_valid_class_name(name) {
+                return bail!(
+                    parser.span(),
+                    "#[class(rename)]: {name:?} is not a valid class name; expected letters, digits and underscores, not starting with a digit"
+                );
+            }
+        }
 
         // #[class(init)], #[class(no_init)]
         match handle_opposite_keys(&mut parser, "init", "class")? {
@@ -302,6 +343,18 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             None => {}
         }
 
+        // #[class(try_init)]
+        if let Some(span) = parser.handle_alone_with_span("try_init")? {
+            if !matches!(init_strategy, InitStrategy::UserDefined) {
+                return bail!(
+                    span,
+                    "#[class(try_init)] cannot be combined with `init` or `no_init`"
+                );
+            }
+
+            init_strategy = InitStrategy::Fallible;
+        }
+
         // #[class(tool)]
         if parser.handle_alone("tool")? {
             is_tool = true;
@@ -389,6 +442,14 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
 
         // #[init]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
+            // With #[class(try_init)], fields are initialized by the user's try_init() function.
+            if matches!(init_strategy, InitStrategy::Fallible) {
+                return bail!(
+                    parser.span(),
+                    "field attribute #[init] cannot be used with #[class(try_init)]; initialize the field in try_init() instead"
+                );
+            }
+
             // #[init] on fields is useless if there is no generated constructor.
             if !matches!(init_strategy, InitStrategy::Generated) {
                 return bail!(
@@ -399,7 +460,42 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
 
             // #[init(default = expr)]
             let default = parser.handle_expr("default")?;
-            field.default = default;
+
+            // #[init(val_fn = path)]
+            let val_fn = parser.handle_expr("val_fn")?;
+
+            // #[init(node = "path")] and #[init(try_node = "path")]
+            let node = parser.handle_expr("node")?;
+            let try_node = parser.handle_expr("try_node")?;
+
+            let given_count = [&default, &val_fn, &node, &try_node]
+                .iter()
+                .filter(|key| key.is_some())
+                .count();
+            if given_count > 1 {
+                return bail!(
+                    parser.span(),
+                    "#[init] keys `default`, `val_fn`, `node` and `try_node` are mutually exclusive"
+                );
+            }
+
+            if (node.is_some() || try_node.is_some()) && !field.is_onready {
+                return bail!(
+                    parser.span(),
+                    "#[init(node)] and #[init(try_node)] require the field to have type `OnReady<T>`"
+                );
+            }
+
+            field.default = if let Some(path) = node {
+                Some(quote! { ::godot::obj::OnReady::node(#path) })
+            } else if let Some(path) = try_node {
+                Some(quote! { ::godot::obj::OnReady::try_node(#path) })
+            } else if let Some(function) = val_fn {
+                // `base` is the parameter of the generated init function, see make_godot_init_impl().
+                Some(quote! { (#function)(&base) })
+            } else {
+                default
+            };
             parser.finish()?;
         }
 
@@ -483,3 +579,12 @@ fn handle_opposite_keys(
         ),
     }
 }
+
+fn is_valid_class_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
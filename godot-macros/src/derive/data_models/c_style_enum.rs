@@ -7,7 +7,7 @@
 
 use proc_macro2::{Ident, Literal, Span, TokenTree};
 
-use crate::util::{bail, error};
+use crate::util::{bail, error, KvParser};
 use crate::ParseResult;
 
 /// Stores info from c-style enums for use in deriving `GodotConvert` and other related traits.
@@ -15,6 +15,8 @@ use crate::ParseResult;
 pub struct CStyleEnum {
     /// The names of each variant.
     enumerator_names: Vec<Ident>,
+    /// The names of each variant as seen by Godot, taking `#[godot(rename = ...)]` into account.
+    enumerator_godot_names: Vec<String>,
     /// The discriminants of each variant, both explicit and implicit.
     enumerator_ords: Vec<Literal>,
 }
@@ -30,10 +32,12 @@ impl CStyleEnum {
             .map(CStyleEnumerator::parse_enum_variant)
             .collect::<ParseResult<Vec<_>>>()?;
 
+        let godot_names = variants.iter().map(|v| v.godot_name.clone()).collect();
         let (names, discriminants) = Self::create_discriminant_mapping(variants)?;
 
         Ok(Self {
             enumerator_names: names,
+            enumerator_godot_names: godot_names,
             enumerator_ords: discriminants,
         })
     }
@@ -52,7 +56,8 @@ impl CStyleEnum {
 
             let discriminant = match enumerator.discriminant_as_i64()? {
                 Some(discriminant) => discriminant,
-                None => last_discriminant.unwrap_or(0) + 1,
+                // First enumerator without explicit discriminant starts at 0.
+                None => last_discriminant.map_or(0, |last| last + 1),
             };
             last_discriminant = Some(discriminant);
 
@@ -71,6 +76,11 @@ impl CStyleEnum {
         &self.enumerator_names
     }
 
+    /// Returns the Godot-facing names of the variants (after renaming), in order of the variants.
+    pub fn godot_names(&self) -> &[String] {
+        &self.enumerator_godot_names
+    }
+
     /// Returns the discriminants of each variant, in order of the variants.
     pub fn discriminants(&self) -> &[Literal] {
         &self.enumerator_ords
@@ -78,7 +88,7 @@ impl CStyleEnum {
 
     /// Return a hint string for use with `PropertyHint::ENUM` where each variant has an explicit integer hint.
     pub fn to_int_hint(&self) -> String {
-        self.enumerator_names
+        self.enumerator_godot_names
             .iter()
             .zip(self.enumerator_ords.iter())
             .map(|(name, discrim)| format!("{name}:{discrim}"))
@@ -88,11 +98,7 @@ impl CStyleEnum {
 
     /// Return a hint string for use with `PropertyHint::ENUM` where the variants are just kept as strings.
     pub fn to_string_hint(&self) -> String {
-        self.enumerator_names
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(",")
+        self.enumerator_godot_names.join(",")
     }
 }
 
@@ -101,6 +107,8 @@ impl CStyleEnum {
 pub struct CStyleEnumerator {
     /// The name of the variant.
     name: Ident,
+    /// The name of the variant in Godot, from `#[godot(rename = ...)]` or else the Rust name.
+    godot_name: String,
     /// The explicit discriminant of the variant, `None` means there was no explicit discriminant.
     discriminant: Option<TokenTree>,
 }
@@ -118,8 +126,19 @@ impl CStyleEnumerator {
             }
         }
 
+        // #[godot(rename = "name")]
+        let rename = match KvParser::parse(&enum_variant.attributes, "godot")? {
+            Some(mut parser) => {
+                let rename = parser.handle_ident_or_string("rename")?;
+                parser.finish()?;
+                rename
+            }
+            None => None,
+        };
+
         Ok(Self {
             name: enum_variant.name.clone(),
+            godot_name: rename.unwrap_or_else(|| enum_variant.name.to_string()),
             discriminant: enum_variant.value.as_ref().map(|val| &val.value).cloned(),
         })
     }
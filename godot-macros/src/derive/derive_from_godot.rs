@@ -72,7 +72,7 @@ fn make_fromgodot_for_int_enum(name: &Ident, enum_: &CStyleEnum, int: &Ident) ->
 /// Derives `FromGodot` for enums with a via type of `GString`.
 fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
     let names = enum_.names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let names_str = enum_.godot_names();
     let bad_variant_error = format!("invalid {name} variant");
 
     quote! {
@@ -71,7 +71,7 @@ fn make_togodot_for_int_enum(name: &Ident, enum_: &CStyleEnum, int: &Ident) -> T
 /// Derives `ToGodot` for enums with a via type of `GString`.
 fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
     let names = enum_.names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let names_str = enum_.godot_names();
 
     quote! {
         impl ::godot::meta::ToGodot for #name {
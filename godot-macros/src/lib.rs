@@ -95,6 +95,86 @@ use crate::util::ident;
 /// # }
 /// ```
 ///
+/// The expression is evaluated separately for each new instance and may refer to the constructor parameter `base` of type
+/// `Base<T>`, e.g. `#[init(default = base.to_gd().get_name())]`. For more involved defaults, `#[init(val_fn = path)]` calls a
+/// function `fn(&Base<T>) -> F` per instance, where `F` is the field type:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct MyStruct {
+///     #[init(val_fn = initial_label)]
+///     label: GString,
+///
+///     base: Base<Node>,
+/// }
+///
+/// fn initial_label(base: &Base<Node>) -> GString {
+///     format!("label of {}", base.to_gd().get_name()).into()
+/// }
+/// ```
+///
+/// Fields of type `OnReady<Gd<T>>` can be bound to a child node with `#[init(node = "path")]`, which expands to
+/// [`OnReady::node("path")`](../obj/struct.OnReady.html#method.node). The node is looked up relative to the base object right
+/// before `ready()`, and a missing node causes a panic. If the child is optional, use `#[init(try_node = "path")]` on a field of
+/// type `OnReady<Option<Gd<T>>>` instead; it stores `None` when the node is absent.
+///
+/// ```
+/// # use godot::prelude::*;
+/// # use godot::classes::{Label, Node2D};
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node)]
+/// struct MyStruct {
+///     #[init(node = "Sprite")]
+///     sprite: OnReady<Gd<Node2D>>,
+///
+///     #[init(try_node = "OptionalLabel")]
+///     label: OnReady<Option<Gd<Label>>>,
+///
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// If construction can fail, use `#[class(try_init)]` and provide an associated function `try_init`, which receives the base and
+/// returns `Result<Self, E>` for any `E: Display`. On error, the message is printed with `godot_error!` and no object is created:
+/// GDScript's `new()` returns `null`, while Rust's `new_alloc()`/`new_gd()` panic.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(try_init, base = Node)]
+/// struct Level {
+///     scene: Gd<PackedScene>,
+///     base: Base<Node>,
+/// }
+///
+/// impl Level {
+///     fn try_init(base: Base<Node>) -> Result<Self, String> {
+///         let scene = try_load::<PackedScene>("res://level.tscn").map_err(|e| e.to_string())?;
+///         Ok(Self { scene, base })
+///     }
+/// }
+/// ```
+///
+/// Field attributes `#[init(...)]` are not available with `try_init`, since `try_init()` initializes all fields itself:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(try_init, base = Node)]
+/// struct Level {
+///     #[init(default = 3)]
+///     lives: i32,
+///     base: Base<Node>,
+/// }
+/// # impl Level {
+/// #     fn try_init(base: Base<Node>) -> Result<Self, String> {
+/// #         Ok(Self { lives: 3, base })
+/// #     }
+/// # }
+/// ```
+///
 /// You can also _disable_ construction from GDScript. This needs to be explicit via `#[class(no_init)]`.
 /// Simply omitting the `init`/`no_init` keys and not overriding your own constructor will cause a compile error.
 ///
@@ -346,6 +426,29 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// For each signal, typed `emit_<signal>()` and `connect_<signal>()` methods are generated. They use the base object, so they
+/// can only be called if the class has a `Base<T>` field:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn health_changed(old: i64, new: i64);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i64) {
+///         self.emit_health_changed(100, 100 - damage);
+///     }
+/// }
+/// ```
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -396,6 +499,16 @@ use crate::util::ident;
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// The new name can also be given as a string literal, e.g. `#[class(rename = "AnimalToad")]`. In both forms, it must be a valid
+/// Godot class name: ASCII letters, digits and underscores, not starting with a digit. Other names are rejected at compile time.
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, rename = "Animal.Toad")]
+/// pub struct Toad {}
+/// ```
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(hidden)]`.
@@ -608,6 +721,30 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Renaming
+///
+/// By default, the Rust function name is used in Godot. Use `#[func(rename = godot_name)]` to register it under a different name.
+/// Names that don't follow Rust conventions can be given as string literals, e.g. `#[func(rename = "doThing")]`.
+///
+/// ## Default parameters
+///
+/// Trailing parameters can be given default values with `#[func(defaults = (param = value, ...))]`. Godot registers them as
+/// default arguments, so callers (including GDScript) may omit them. Each value is an expression of the parameter's type.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {}
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func(defaults = (delay = 1.5))]
+///     fn spawn(&self, count: i64, delay: f64) {
+///         // ...
+///     }
+/// }
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -748,6 +885,23 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::C.to_godot(), GString::from("C"));
 /// ```
 ///
+/// The name used for a variant can be changed with `#[godot(rename = "...")]`. For `GString` enums, this is the string it converts
+/// to and from; for integer enums, it affects the name shown in the editor when exporting.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// #[derive(GodotConvert)]
+/// #[godot(via = GString)]
+/// enum State {
+///     #[godot(rename = "idle")]
+///     Idle,
+///     #[godot(rename = "run-fast")]
+///     Running,
+/// }
+///
+/// assert_eq!(State::Running.to_godot(), GString::from("run-fast"));
+/// ```
+///
 /// ```no_run
 /// use godot::prelude::*;
 /// #[derive(GodotConvert)]
@@ -105,7 +105,15 @@
 //! * **`codegen-rustfmt`**
 //!
 //!   Use rustfmt to format generated binding code. Because rustfmt is so slow, this is detrimental to initial compile time.
-//!   Without it, we use a lightweight and fast custom formatter to enable basic human readability.
+//!   Without it, we use a lightweight and fast custom formatter to enable basic human readability. That formatter can be turned off
+//!   without changing Cargo features, by setting the environment variable `GDEXT_CODEGEN_FMT=0`. Changing the variable makes
+//!   the build scripts re-run, so the bindings are regenerated on the next build.<br><br>
+//!
+//! * **`no-editor-classes`**
+//!
+//!   Do not generate bindings for editor-only engine classes such as `EditorPlugin` or `EditorInterface`, reducing compile times.
+//!   Only enable this if your extension never runs code inside the editor: it is incompatible with `#[class(tool)]` classes that
+//!   interact with the editor, and with `#[class(editor_plugin)]`.
 //!
 //! _Integrations:_
 //!
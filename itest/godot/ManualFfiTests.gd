@@ -307,6 +307,13 @@ func test_func_rename():
 	assert_eq(func_rename.has_method("spell_static"), true)
 	assert_eq(func_rename.spell_static(), "static")
 
+func test_func_default_args():
+	var obj := FuncObj.new()
+
+	assert_eq(obj.describe_spawn("orc"), "orc:3:1.5")
+	assert_eq(obj.describe_spawn("orc", 7), "orc:7:1.5")
+	assert_eq(obj.describe_spawn("orc", 7, 0.25), "orc:7:0.25")
+
 var gd_self_obj: GdSelfObj
 func update_self_reference(value):
 	gd_self_obj.update_internal(value)
@@ -360,3 +367,9 @@ func test_get_set():
 	assert_eq(obj.set_get, 1000)
 	assert(obj.is_set_called())
 	assert(obj.is_get_called())
+
+func test_constants():
+	assert_eq(HasConstants.A, 128)
+	assert_eq(HasConstants.B, -600)
+	assert_eq(HasConstants.C, 255)
+	assert_eq(HasConstants.D, 20 + 33 * 45)
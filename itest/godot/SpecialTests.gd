@@ -55,3 +55,35 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Deferred emissions only happen at idle time, so frames need to pass between emitting and checking.
+func test_signal_emit_deferred():
+	var emitter := TypedEmitter.new()
+	var received: Array = []
+	emitter.value_changed.connect(func(value, _label): received.push_back(value))
+
+	emitter.change_value_deferred(7)
+	assert_eq(received, [], "Deferred emission should not happen immediately")
+
+	await _let_idle_time_pass()
+	assert_eq(received, [7], "Deferred emission should happen at idle time")
+
+	# Freeing the emitter cancels a pending deferred emission.
+	emitter.change_value_deferred(8)
+	emitter.free()
+
+	await _let_idle_time_pass()
+	assert_eq(received, [7], "Deferred emission should be dropped for a freed emitter")
+
+func test_callable_call_deferred():
+	var obj := CallableTestObj.new()
+
+	CallableTestObj.defer_call(obj.foo, 42)
+	assert_eq(obj.value, 0, "Deferred call should not happen immediately")
+
+	await _let_idle_time_pass()
+	assert_eq(obj.value, 42, "Deferred call should happen at idle time")
+
+func _let_idle_time_pass():
+	var tree: SceneTree = Engine.get_main_loop()
+	await tree.process_frame
+	await tree.process_frame
@@ -17,6 +17,24 @@ fn color_from_rgba8() {
     );
 }
 
+#[itest]
+fn color_from_rgba8_pure_red() {
+    assert_eq!(Color::from_rgba8(255, 0, 0, 255), Color::RED);
+    assert_eq!(
+        Color::from_rgba8(255, 0, 0, 255),
+        Color::from_rgb(1.0, 0.0, 0.0)
+    );
+}
+
+#[itest]
+fn color_from_hex() {
+    assert_eq!(Color::from_hex(0xff0000ff), Color::RED);
+    assert_eq!(
+        Color::from_hex(0x01020304),
+        Color::from_u32_rgba(0x01020304, ColorChannelOrder::RGBA)
+    );
+}
+
 #[itest]
 fn color_from_u32() {
     const D: f32 = 255.0;
@@ -147,6 +165,17 @@ fn color_from_color_hsv() {
     }
 }
 
+#[itest]
+fn color_from_hsv_roundtrip() {
+    for (r, g, b) in COLOR_HSV_CASES_RGB {
+        let original = Color::from_rgb(r, g, b).with_alpha(0.5);
+        let hsv = original.to_hsv();
+
+        let back = Color::from_hsv(hsv.h as f64, hsv.s as f64, hsv.v as f64).with_alpha(hsv.a);
+        assert_eq_approx!(original, back, "r: {r}, g: {g}, b: {b}");
+    }
+}
+
 #[itest]
 fn color_hsv_wraps_correctly() {
     for (hue_origin, hue_shift, hue_expected) in [
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::meta::ArrayElement;
 use godot::prelude::*;
 
 use crate::framework::{expect_panic, itest};
@@ -12,6 +13,8 @@ use crate::framework::{expect_panic, itest};
 #[itest]
 fn array_default() {
     assert_eq!(VariantArray::default().len(), 0);
+    assert_eq!(VariantArray::default(), VariantArray::new());
+    assert_eq!(Array::<i64>::default(), Array::<i64>::new());
 }
 
 #[itest]
@@ -37,6 +40,44 @@ fn typed_array_from_to_variant() {
     assert_eq!(result, array);
 }
 
+#[itest]
+fn typed_array_of_builtin_structs() {
+    fn check_roundtrip<T>(values: [T; 2])
+    where
+        T: ArrayElement + ToGodot + FromGodot + PartialEq + std::fmt::Debug + Clone,
+    {
+        let mut array = Array::<T>::new();
+        for value in values.iter() {
+            array.push(value.clone());
+        }
+
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.at(0), values[0]);
+        assert_eq!(array.get(1), Some(values[1].clone()));
+
+        let variant = array.to_variant();
+        let back = Array::<T>::try_from_variant(&variant).expect("typed array conversion");
+        assert_eq!(back, array);
+    }
+
+    check_roundtrip([
+        Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)),
+        Rect2::default(),
+    ]);
+    check_roundtrip([
+        Rect2i::new(Vector2i::new(-1, 2), Vector2i::new(30, 40)),
+        Rect2i::default(),
+    ]);
+    check_roundtrip([
+        Transform2D::IDENTITY,
+        Transform2D::from_angle_origin(0.0, Vector2::new(5.0, -5.0)),
+    ]);
+    check_roundtrip([
+        Plane::new(Vector3::UP, 2.0),
+        Plane::new(Vector3::RIGHT, -1.0),
+    ]);
+}
+
 #[itest]
 fn untyped_array_from_to_variant() {
     let array = varray![1, 2];
@@ -66,6 +107,22 @@ fn array_from_iterator() {
     assert_eq!(array.at(1), 2);
 }
 
+#[itest]
+fn array_try_from_iterator() {
+    let items: Vec<Result<i64, String>> = vec![Ok(1), Ok(2), Ok(3)];
+    let array = Array::try_from_iter(items);
+    assert_eq!(array, Ok(array![1, 2, 3]));
+
+    let items: Vec<Result<i64, String>> = vec![Ok(1), Err("bad".to_string()), Ok(3)];
+    let result = Array::try_from_iter(items);
+    assert_eq!(result, Err("bad".to_string()));
+
+    // Also available through `collect()`.
+    let collected: Result<Array<i64>, &str> =
+        [Ok(4), Err("first"), Err("second")].into_iter().collect();
+    assert_eq!(collected, Err("first"));
+}
+
 #[itest]
 fn array_from_slice() {
     let array = Array::from(&[1, 2]);
@@ -84,6 +141,24 @@ fn array_try_into_vec() {
     assert_eq!(result, Ok(vec![1, 2]));
 }
 
+#[itest]
+fn array_to_vec_lossy() {
+    let array = varray![1, "two", 3, 4.5, Variant::nil(), -6, i64::MAX];
+
+    let (ints, skipped) = array.to_vec_lossy::<i64>();
+    assert_eq!(ints, vec![1, 3, -6, i64::MAX]);
+    assert_eq!(skipped, 3);
+
+    // Narrower target type: out-of-range values are skipped as well.
+    let (small, skipped) = array.to_vec_lossy::<i8>();
+    assert_eq!(small, vec![1, 3, -6]);
+    assert_eq!(skipped, 4);
+
+    let (empty, skipped) = VariantArray::new().to_vec_lossy::<i64>();
+    assert!(empty.is_empty());
+    assert_eq!(skipped, 0);
+}
+
 #[itest]
 fn array_iter_shared() {
     let array = array![1, 2];
@@ -207,6 +282,24 @@ fn array_binary_search() {
     assert_eq!(array.bsearch(&4), 2);
 }
 
+#[itest]
+fn array_binary_search_after_sort() {
+    let mut array: Array<i64> = array![40, -7, 12, 3, 25];
+    array.sort_unstable();
+    assert_eq!(array, array![-7, 3, 12, 25, 40]);
+
+    // Present values yield their index.
+    for (index, value) in array.iter_shared().enumerate() {
+        assert_eq!(array.bsearch(&value), index);
+    }
+
+    // Absent values yield the insertion index that keeps the array sorted.
+    assert_eq!(array.bsearch(&-100), 0);
+    assert_eq!(array.bsearch(&5), 2);
+    assert_eq!(array.bsearch(&26), 4);
+    assert_eq!(array.bsearch(&1000), 5);
+}
+
 #[itest]
 fn array_find() {
     let array = array![1, 2, 1];
@@ -497,6 +590,37 @@ fn array_binary_search_custom() {
     assert_eq!(a.bsearch_custom(&3, func), 2);
 }
 
+#[itest]
+fn array_dedup() {
+    let mut a = array![1, 1, 2, 3, 3, 3];
+    a.dedup();
+    assert_eq!(a, array![1, 2, 3]);
+
+    // Only consecutive duplicates are removed.
+    let mut a = array![1, 2, 1, 1];
+    a.dedup();
+    assert_eq!(a, array![1, 2, 1]);
+
+    let mut a = Array::<i32>::new();
+    a.dedup();
+    assert!(a.is_empty());
+}
+
+#[itest]
+fn array_retain() {
+    let mut a = array![1, 2, 3, 4, 5, 6];
+    a.retain(|x| x % 2 == 0);
+    assert_eq!(a, array![2, 4, 6]);
+
+    let mut a = array![GString::from("keep"), GString::from("drop")];
+    a.retain(|s| s != &GString::from("drop"));
+    assert_eq!(a, array![GString::from("keep")]);
+
+    let mut a = array![1, 3, 5];
+    a.retain(|x| x % 2 == 0);
+    assert!(a.is_empty());
+}
+
 #[itest]
 fn array_shrink() {
     let mut a = array![1, 5, 4, 3, 8];
@@ -544,6 +668,49 @@ fn array_resize() {
     assert_eq!(a, array![GString::from("hello"), GString::from("bar"),]);
 }
 
+#[itest]
+fn array_resize_with() {
+    let mut a = array![1, 2];
+
+    let mut next = 10;
+    a.resize_with(5, || {
+        next += 1;
+        next
+    });
+    assert_eq!(a, array![1, 2, 11, 12, 13]);
+
+    a.resize_with(3, || panic!("must not be called when truncating"));
+    assert_eq!(a, array![1, 2, 11]);
+}
+
+#[itest]
+fn array_resize_with_panic() {
+    let mut a = array![1, 2];
+
+    let mut calls = 0;
+    expect_panic("resize_with() callback panics", || {
+        a.resize_with(5, || {
+            calls += 1;
+            if calls == 2 {
+                panic!("second element fails");
+            }
+            calls * 10
+        });
+    });
+
+    // Elements produced before the panic are kept, and no NIL placeholders are left behind.
+    assert_eq!(a, array![1, 2, 10]);
+}
+
+#[itest]
+fn array_fill() {
+    let mut a: Array<i64> = Array::new();
+    a.resize_with(4, || 0);
+    a.fill(&7);
+
+    assert_eq!(a, array![7, 7, 7, 7]);
+}
+
 #[derive(GodotClass, Debug)]
 #[class(init, base=RefCounted)]
 struct ArrayTest;
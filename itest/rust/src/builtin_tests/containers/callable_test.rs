@@ -6,7 +6,8 @@
  */
 
 use godot::builtin::inner::InnerCallable;
-use godot::builtin::{varray, Callable, GString, StringName, Variant};
+use godot::builtin::{varray, Callable, GString, Signal, StringName, Variant};
+use godot::classes::object::ConnectFlags;
 use godot::classes::{Node2D, Object};
 use godot::meta::ToGodot;
 use godot::obj::{NewAlloc, NewGd};
@@ -17,6 +18,7 @@ use crate::framework::itest;
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
 struct CallableTestObj {
+    #[var]
     value: i32,
 }
 
@@ -31,6 +33,20 @@ impl CallableTestObj {
     fn bar(&self, b: i32) -> GString {
         b.to_variant().stringify()
     }
+
+    #[func]
+    fn assign_weighted(&mut self, a: i32, weight: i32) {
+        self.value = a * weight;
+    }
+
+    /// Used by GDScript tests, which can let frames pass before checking the call.
+    #[func]
+    fn defer_call(callable: Callable, a: i32) {
+        callable.call_deferred(&[a.to_variant()]);
+    }
+
+    #[signal]
+    fn value_emitted(a: i32);
 }
 
 #[itest]
@@ -56,6 +72,28 @@ fn callable_validity() {
     assert!(Callable::invalid().object().is_none());
 }
 
+#[itest]
+fn callable_validity_freed_object() {
+    let obj = Node2D::new_alloc();
+    let callable = obj.callable("set_position");
+    assert!(callable.is_valid());
+
+    obj.free();
+    assert!(!callable.is_valid());
+}
+
+#[itest]
+fn callable_call_deferred() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("foo");
+
+    callable.call_deferred(&[42.to_variant()]);
+
+    // The call only happens at idle time, not within the current frame. The call itself is tested in SpecialTests.gd
+    // (test_callable_call_deferred), since Rust itests cannot let frames pass.
+    assert_eq!(obj.bind().value, 0);
+}
+
 #[itest]
 fn callable_hash() {
     let obj = CallableTestObj::new_gd();
@@ -143,6 +181,31 @@ fn callable_bindv() {
     );
 }
 
+#[itest]
+fn callable_bind() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("bar");
+    let callable_bound = callable.bind(&[7.to_variant()]);
+
+    assert_eq!(
+        callable_bound.callv(varray![]),
+        7.to_variant().stringify().to_variant()
+    );
+}
+
+#[itest]
+fn callable_bind_signal_connect() {
+    let obj = CallableTestObj::new_gd();
+    let signal = Signal::from_object_signal(&obj, "value_emitted");
+
+    // Bound arguments are appended after the emitted ones.
+    let handler = obj.callable("assign_weighted").bind(&[3.to_variant()]);
+    signal.connect_flags(handler, ConnectFlags::from_ord(0));
+
+    signal.emit(&[5.to_variant()]);
+    assert_eq!(obj.bind().value, 15);
+}
+
 // Testing https://github.com/godot-rust/gdext/issues/410
 
 #[derive(GodotClass)]
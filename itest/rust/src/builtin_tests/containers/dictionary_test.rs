@@ -7,7 +7,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{array, dict, varray, Array, Dictionary, GString, Variant};
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
 
@@ -16,6 +16,7 @@ use crate::framework::{expect_panic, itest};
 #[itest]
 fn dictionary_default() {
     assert_eq!(Dictionary::default().len(), 0);
+    assert_eq!(Dictionary::default(), Dictionary::new());
 }
 
 #[itest]
@@ -53,6 +54,26 @@ fn dictionary_from() {
     assert_eq!(dictionary.get(2), Some("bar".to_variant()), "key = \"bar\"");
 }
 
+#[itest]
+fn dictionary_try_into_hash_map() {
+    let map = HashMap::from([("foo".to_string(), 1), ("bar".to_string(), 2)]);
+
+    let dictionary = Dictionary::from(&map);
+    let back = HashMap::<String, i64>::try_from(dictionary).expect("round-trip");
+    assert_eq!(back, map);
+
+    let dictionary: Dictionary = map.clone().into_iter().collect();
+    let back: HashMap<String, i64> = dictionary.try_into().expect("round-trip");
+    assert_eq!(back, map);
+
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": "not an integer",
+    };
+    let result = HashMap::<String, i64>::try_from(dictionary);
+    assert!(result.is_err());
+}
+
 #[itest]
 fn dictionary_macro() {
     let dictionary = dict! {
@@ -226,6 +247,44 @@ fn dictionary_at() {
     });
 }
 
+#[itest]
+fn dictionary_get_as() {
+    let dictionary = dict! {
+        "foo": 0,
+        "baz": "foobar",
+    };
+
+    assert_eq!(dictionary.get_as::<_, i64>("foo"), Some(0));
+    assert_eq!(
+        dictionary.get_as::<_, GString>("baz"),
+        Some(GString::from("foobar"))
+    );
+    assert_eq!(dictionary.get_as::<_, i64>("missing"), None);
+    expect_panic("type mismatch in get_as()", || {
+        dictionary.get_as::<_, i64>("baz");
+    });
+}
+
+#[itest]
+fn dictionary_at_as() {
+    let dictionary = dict! {
+        "foo": 0,
+        "baz": "foobar",
+    };
+
+    assert_eq!(dictionary.at_as::<_, i64>("foo"), 0);
+    assert_eq!(
+        dictionary.at_as::<_, GString>("baz"),
+        GString::from("foobar")
+    );
+    expect_panic("missing key in at_as()", || {
+        dictionary.at_as::<_, i64>("missing");
+    });
+    expect_panic("type mismatch in at_as()", || {
+        dictionary.at_as::<_, i64>("baz");
+    });
+}
+
 #[itest]
 fn dictionary_insert() {
     let mut dictionary = dict! {
@@ -276,6 +335,67 @@ fn dictionary_insert_long() {
     );
 }
 
+#[itest]
+fn dictionary_entry_or_insert() {
+    let mut dictionary = dict! {
+        "foo": 0,
+    };
+
+    assert!(dictionary.entry("foo").is_occupied());
+    assert_eq!(dictionary.entry("foo").or_insert(10), 0.to_variant());
+    assert_eq!(dictionary.get("foo"), Some(0.to_variant()));
+
+    assert!(!dictionary.entry("bar").is_occupied());
+    assert_eq!(dictionary.entry("bar").or_insert(10), 10.to_variant());
+    assert_eq!(dictionary.get("bar"), Some(10.to_variant()));
+    assert_eq!(dictionary.len(), 2);
+}
+
+#[itest]
+fn dictionary_entry_or_insert_with() {
+    let mut dictionary = dict! {
+        "foo": 0,
+    };
+
+    let value = dictionary
+        .entry("foo")
+        .or_insert_with(|| -> i64 { panic!("must not be called for present key") });
+    assert_eq!(value, 0.to_variant());
+
+    let value = dictionary.entry("bar").or_insert_with(|| "new");
+    assert_eq!(value, "new".to_variant());
+    assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
+}
+
+#[itest]
+fn dictionary_entry_and_modify() {
+    let mut dictionary = dict! {
+        "foo": 1,
+    };
+
+    let increment = |value: &mut Variant| *value = (value.to::<i64>() + 1).to_variant();
+
+    let value = dictionary.entry("foo").and_modify(increment).or_insert(0);
+    assert_eq!(value, 2.to_variant());
+
+    let value = dictionary.entry("bar").and_modify(increment).or_insert(0);
+    assert_eq!(value, 0.to_variant());
+    assert_eq!(dictionary.get("foo"), Some(2.to_variant()));
+    assert_eq!(dictionary.get("bar"), Some(0.to_variant()));
+}
+
+#[itest]
+fn dictionary_entry_nil_value() {
+    let mut dictionary = dict! {
+        "foo": Variant::nil(),
+    };
+
+    // A stored NIL is a present value, not an absent key.
+    assert!(dictionary.entry("foo").is_occupied());
+    assert_eq!(dictionary.entry("foo").or_insert(10), Variant::nil());
+    assert_eq!(dictionary.get("foo"), Some(Variant::nil()));
+}
+
 #[itest]
 fn dictionary_extend() {
     let mut dictionary = dict! {
@@ -301,6 +421,42 @@ fn dictionary_extend() {
     assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
 }
 
+#[itest]
+fn dictionary_merged() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": true,
+    };
+    let other = dict! {
+        "bar": "new",
+        "baz": Variant::nil(),
+    };
+
+    let merged = dictionary.merged(&other, false);
+    assert_eq!(
+        merged,
+        dict! {
+            "foo": 0,
+            "bar": true,
+            "baz": Variant::nil(),
+        }
+    );
+
+    let merged = dictionary.merged(&other, true);
+    assert_eq!(
+        merged,
+        dict! {
+            "foo": 0,
+            "bar": "new",
+            "baz": Variant::nil(),
+        }
+    );
+
+    // Inputs are unchanged.
+    assert_eq!(dictionary, dict! { "foo": 0, "bar": true });
+    assert_eq!(other, dict! { "bar": "new", "baz": Variant::nil() });
+}
+
 #[itest]
 fn dictionary_remove() {
     let mut dictionary = dict! {
@@ -311,6 +467,31 @@ fn dictionary_remove() {
     assert!(dictionary.is_empty());
 }
 
+#[itest]
+fn dictionary_retain() {
+    let mut dictionary = dict! {
+        "foo": 1,
+        "bar": 5,
+        "baz": 10,
+        "qux": "text",
+    };
+
+    // Only remove integers below 5.
+    dictionary.retain(|_key, value| match value.try_to::<i64>() {
+        Ok(int) => int >= 5,
+        Err(_) => true,
+    });
+
+    assert_eq!(dictionary.len(), 3);
+    assert!(!dictionary.contains_key("foo"));
+    assert_eq!(dictionary.get("bar"), Some(5.to_variant()));
+    assert_eq!(dictionary.get("baz"), Some(10.to_variant()));
+    assert_eq!(dictionary.get("qux"), Some("text".to_variant()));
+
+    dictionary.retain(|_key, _value| false);
+    assert!(dictionary.is_empty());
+}
+
 #[itest]
 fn dictionary_clear() {
     let mut dictionary = dict! {
@@ -366,6 +547,31 @@ fn dictionary_keys_values() {
     assert_eq!(dictionary.values_array(), varray![0, true]);
 }
 
+#[itest]
+fn dictionary_keys_values_typed() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": 2,
+    };
+
+    let keys: Array<GString> = dictionary.keys_array_typed().unwrap();
+    assert_eq!(keys, array![GString::from("foo"), GString::from("bar")]);
+
+    let values = dictionary.values_array_typed::<i64>().unwrap();
+    assert_eq!(values, array![1, 2]);
+
+    let mixed = dict! {
+        "foo": 1,
+        "bar": "not an int",
+    };
+    mixed
+        .values_array_typed::<i64>()
+        .expect_err("string value is not an i64");
+    mixed
+        .keys_array_typed::<i64>()
+        .expect_err("string keys are not i64");
+}
+
 #[itest]
 fn dictionary_equal() {
     assert_eq!(dict! {"foo": "bar"}, dict! {"foo": "bar"});
@@ -399,6 +605,60 @@ fn dictionary_iter() {
     assert_eq!(map, map2);
 }
 
+#[itest]
+fn dictionary_iter_try_typed() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": "not an int",
+        "baz": 2,
+    };
+
+    let results: Vec<_> = dictionary
+        .iter_shared()
+        .try_typed::<GString, i64>()
+        .collect();
+    assert_eq!(results.len(), 3);
+
+    let (ok, err): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+    assert_eq!(ok.len(), 2);
+    assert_eq!(err.len(), 1);
+
+    let err = err.into_iter().next().unwrap().unwrap_err();
+    assert_eq!(err.value(), Some(&"not an int".to_variant()));
+
+    let map: Result<HashMap<GString, i64>, _> = dict! { "foo": 0, "baz": 2 }
+        .iter_shared()
+        .try_typed()
+        .collect();
+    assert_eq!(
+        map.unwrap(),
+        HashMap::from([("foo".into(), 0), ("baz".into(), 2)])
+    );
+}
+
+#[itest]
+fn dictionary_values_shared() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": 1,
+        "baz": 2,
+    };
+
+    assert_eq!(dictionary.values_shared().count(), dictionary.len());
+    assert_eq!(dictionary.values_shared().size_hint(), (3, Some(3)));
+
+    let values: HashSet<i64> = dictionary.values_shared().typed().collect();
+    assert_eq!(values, HashSet::from([0, 1, 2]));
+
+    let values: Vec<Variant> = dictionary.values_shared().collect();
+    assert_eq!(
+        values,
+        dictionary.values_array().iter_shared().collect::<Vec<_>>()
+    );
+
+    assert_eq!(Dictionary::new().values_shared().count(), 0);
+}
+
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
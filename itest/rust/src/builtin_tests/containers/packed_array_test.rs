@@ -6,11 +6,16 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::{PackedByteArray, PackedFloat32Array, PackedStringArray};
+use godot::builtin::{
+    Array, GString, PackedByteArray, PackedFloat32Array, PackedInt64Array, PackedStringArray,
+    ToPacked,
+};
 
 #[itest]
 fn packed_array_default() {
     assert_eq!(PackedByteArray::default().len(), 0);
+    assert_eq!(PackedByteArray::default(), PackedByteArray::new());
+    assert_eq!(PackedStringArray::default(), PackedStringArray::new());
 }
 
 #[itest]
@@ -35,6 +40,44 @@ fn packed_array_to_vec() {
     assert_eq!(array.to_vec(), vec![1, 2]);
 }
 
+#[itest]
+fn packed_array_to_packed() {
+    let vec: Vec<i64> = (0..1000).collect();
+    let packed: PackedInt64Array = vec.to_packed();
+    let array: Array<i64> = Array::from(vec.as_slice());
+
+    assert_eq!(packed.len(), vec.len());
+    assert_eq!(packed.len(), array.len());
+    assert_eq!(packed.as_slice(), vec.as_slice());
+
+    let floats = [1.5f32, -2.0, 0.25];
+    assert_eq!(
+        floats.to_packed(),
+        PackedFloat32Array::from(&[1.5, -2.0, 0.25])
+    );
+
+    let strings = [GString::from("a"), GString::from("bc")];
+    let packed: PackedStringArray = strings.to_packed();
+    assert_eq!(packed.to_vec(), strings);
+
+    let empty: Vec<u8> = Vec::new();
+    assert!(empty.to_packed().is_empty());
+}
+
+#[itest]
+fn packed_byte_array_round_trip() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let array = PackedByteArray::from(bytes.as_slice());
+    assert_eq!(array.len(), bytes.len());
+    assert_eq!(array.as_slice(), bytes.as_slice());
+    assert_eq!(array.to_vec(), bytes);
+
+    let empty = PackedByteArray::from(&[][..]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_slice(), &[] as &[u8]);
+    assert_eq!(empty.to_vec(), Vec::<u8>::new());
+}
+
 /*
 #[itest(skip)]
 fn packed_array_into_iterator() {
@@ -235,3 +278,69 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_byte_array_encode_decode() {
+    let mut a = PackedByteArray::new();
+    a.resize(16);
+
+    assert_eq!(a.encode_u8(0, 0xAB), Ok(()));
+    assert_eq!(a.decode_u8(0), Ok(0xAB));
+
+    assert_eq!(a.encode_i8(1, -5), Ok(()));
+    assert_eq!(a.decode_i8(1), Ok(-5));
+
+    assert_eq!(a.encode_u16(2, 0xBEEF), Ok(()));
+    assert_eq!(a.decode_u16(2), Ok(0xBEEF));
+
+    assert_eq!(a.encode_i16(2, -1234), Ok(()));
+    assert_eq!(a.decode_i16(2), Ok(-1234));
+
+    assert_eq!(a.encode_u32(4, 0xDEAD_BEEF), Ok(()));
+    assert_eq!(a.decode_u32(4), Ok(0xDEAD_BEEF));
+
+    assert_eq!(a.encode_i32(4, -123_456), Ok(()));
+    assert_eq!(a.decode_i32(4), Ok(-123_456));
+
+    assert_eq!(a.encode_u64(8, u64::MAX - 1), Ok(()));
+    assert_eq!(a.decode_u64(8), Ok(u64::MAX - 1));
+
+    assert_eq!(a.encode_i64(8, i64::MIN), Ok(()));
+    assert_eq!(a.decode_i64(8), Ok(i64::MIN));
+
+    assert_eq!(a.encode_f32(12, 1.5), Ok(()));
+    assert_eq!(a.decode_f32(12), Ok(1.5));
+
+    assert_eq!(a.encode_f64(8, -0.25), Ok(()));
+    assert_eq!(a.decode_f64(8), Ok(-0.25));
+
+    // Little-endian layout.
+    a.encode_u32(0, 0x0403_0201).unwrap();
+    assert_eq!(a.subarray(0, 4).to_vec(), vec![1, 2, 3, 4]);
+}
+
+#[itest]
+fn packed_byte_array_encode_decode_out_of_bounds() {
+    let mut a = PackedByteArray::from(&[0; 6]);
+
+    assert!(a.encode_u8(6, 1).is_err());
+    assert!(a.decode_u8(6).is_err());
+
+    assert!(a.encode_u32(3, 1).is_err());
+    assert!(a.decode_u32(3).is_err());
+    assert!(a.encode_f64(0, 1.0).is_err());
+    assert!(a.decode_f64(0).is_err());
+    assert!(a.decode_i32(usize::MAX).is_err());
+
+    // Failed writes leave the array untouched.
+    assert_eq!(a.to_vec(), vec![0; 6]);
+
+    let err = a.decode_u32(3).unwrap_err();
+    assert_eq!(
+        (err.byte_offset(), err.value_size(), err.array_len()),
+        (3, 4, 6)
+    );
+
+    let empty = PackedByteArray::new();
+    assert!(empty.decode_u8(0).is_err());
+}
@@ -11,8 +11,10 @@ use godot::builtin::{Callable, GString, Signal, StringName, Variant};
 use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 
+use godot::classes::object::ConnectFlags;
 use godot::classes::{Object, RefCounted};
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::global::Error;
+use godot::obj::{Base, EngineBitfield, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::sys;
 
 use crate::framework::itest;
@@ -64,6 +66,45 @@ impl Receiver {
 
 const SIGNAL_ARG_STRING: &str = "Signal string arg";
 
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedEmitter {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl TypedEmitter {
+    #[signal]
+    fn value_changed(value: i64, label: GString);
+
+    #[func]
+    fn change_value(&mut self, value: i64) {
+        let args = [value.to_variant(), "from func".to_variant()];
+        self.emit_signal_self("value_changed".into(), &args);
+    }
+
+    /// Used by GDScript tests, which can let frames pass before checking the emission.
+    #[func]
+    fn change_value_deferred(&mut self, value: i64) {
+        let signal = Signal::from_object_signal(&self.to_gd(), "value_changed");
+        signal.emit_deferred(&[value.to_variant(), "deferred".to_variant()]);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedReceiver {
+    received: Vec<(i64, GString)>,
+}
+
+#[godot_api]
+impl TypedReceiver {
+    #[func]
+    fn on_value_changed(&mut self, value: i64, label: GString) {
+        self.received.push((value, label));
+    }
+}
+
 #[itest]
 /// Test that godot can call a method that is connect with a signal
 fn signals() {
@@ -93,6 +134,70 @@ fn signals() {
     emitter.free();
 }
 
+#[itest]
+fn signal_typed_emit_connect() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let receiver = TypedReceiver::new_alloc();
+
+    let error = emitter
+        .bind_mut()
+        .connect_value_changed(receiver.callable("on_value_changed"));
+    assert_eq!(error, Error::OK);
+
+    emitter.bind_mut().emit_value_changed(7, "seven".into());
+    emitter
+        .bind_mut()
+        .emit_value_changed(-1, "minus one".into());
+
+    assert_eq!(
+        receiver.bind().received,
+        vec![(7, "seven".into()), (-1, "minus one".into())]
+    );
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_emit_self_from_func() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let receiver = TypedReceiver::new_alloc();
+
+    emitter.connect(
+        "value_changed".into(),
+        receiver.callable("on_value_changed"),
+    );
+    emitter.call("change_value".into(), &[33.to_variant()]);
+
+    assert_eq!(receiver.bind().received, vec![(33, "from func".into())]);
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_emit_tuple() {
+    let emitter = TypedEmitter::new_alloc();
+    let receiver = TypedReceiver::new_alloc();
+
+    let signal = Signal::from_object_signal(&emitter, "value_changed");
+    signal.connect_flags(
+        receiver.callable("on_value_changed"),
+        ConnectFlags::from_ord(0),
+    );
+
+    signal.emit_tuple((7i64, GString::from("typed")));
+    signal.emit_tuple((8, "str slice"));
+
+    assert_eq!(
+        receiver.bind().received,
+        vec![(7, "typed".into()), (8, "str slice".into())]
+    );
+
+    receiver.free();
+    emitter.free();
+}
+
 #[itest]
 fn instantiate_signal() {
     let mut object = RefCounted::new_gd();
@@ -130,6 +235,29 @@ fn emit_signal() {
     receiver.free();
 }
 
+#[itest]
+fn emit_signal_deferred() {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let receiver = Receiver::new_alloc();
+
+    object.connect(
+        StringName::from("test_signal"),
+        Callable::from_object_method(&receiver, "receive_1_arg"),
+    );
+
+    signal.emit_deferred(&[987i64.to_variant()]);
+
+    // Emission only happens at idle time, not within the current frame. Delivery after idle time is tested in
+    // SpecialTests.gd (test_signal_emit_deferred), since Rust itests cannot let frames pass.
+    assert!(!receiver.bind().used[1].get());
+
+    object.free();
+    receiver.free();
+}
+
 #[itest]
 fn connect_signal() {
     let mut object = RefCounted::new_gd();
@@ -139,7 +267,10 @@ fn connect_signal() {
     let signal = Signal::from_object_signal(&object, "test_signal");
     let receiver = Receiver::new_alloc();
 
-    signal.connect(Callable::from_object_method(&receiver, "receive_1_arg"), 0);
+    signal.connect_flags(
+        Callable::from_object_method(&receiver, "receive_1_arg"),
+        ConnectFlags::from_ord(0),
+    );
 
     assert_eq!(signal.connections().len(), 1);
 
@@ -149,3 +280,124 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn connect_signal_to_rust_fn() {
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Arc;
+
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+
+    let received = Arc::new(AtomicI64::new(0));
+    let received_in_fn = received.clone();
+    let callable = Callable::from_fn("receive", move |args: &[&Variant]| {
+        received_in_fn.fetch_add(args[0].to::<i64>(), Ordering::SeqCst);
+        Ok(Variant::nil())
+    });
+
+    signal.connect_flags(callable.clone(), ConnectFlags::from_ord(0));
+    assert!(signal.is_connected(callable));
+
+    signal.emit(&[987i64.to_variant()]);
+    assert_eq!(received.load(Ordering::SeqCst), 987);
+
+    signal.emit(&[13i64.to_variant()]);
+    assert_eq!(received.load(Ordering::SeqCst), 1000);
+}
+
+#[itest]
+fn connect_signal_with_flags() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let receiver = Receiver::new_alloc();
+    let callable = Callable::from_object_method(&receiver, "receive_1_arg");
+
+    let flags = ConnectFlags::ONE_SHOT | ConnectFlags::DEFERRED;
+    let error = signal.connect_flags(callable.clone(), flags);
+    assert_eq!(error, Error::OK);
+    assert!(signal.is_connected(callable.clone()));
+
+    let connections = signal.connections();
+    assert_eq!(connections.len(), 1);
+
+    let stored_flags = connections.get(0).get("flags").unwrap().to::<u64>();
+    assert_eq!(stored_flags, flags.ord());
+
+    signal.disconnect(callable);
+    receiver.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod signal_future {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use godot::builtin::{Signal, Variant};
+    use godot::classes::{Object, RefCounted};
+    use godot::meta::ToGodot;
+    use godot::obj::NewGd;
+
+    use crate::framework::itest;
+
+    #[derive(Default)]
+    struct CountingWaker {
+        wake_count: AtomicUsize,
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[itest]
+    fn signal_future_resolves_on_emit() {
+        let mut object = RefCounted::new_gd();
+        object.add_user_signal("test_signal".into());
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        let mut future = signal.to_future();
+        assert_eq!(signal.connections().len(), 1);
+
+        let counting = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counting.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+        assert_eq!(counting.wake_count.load(Ordering::SeqCst), 0);
+
+        let arg = Variant::from(object.clone().upcast::<Object>());
+        signal.emit(&[987i64.to_variant(), arg.clone()]);
+        assert_eq!(counting.wake_count.load(Ordering::SeqCst), 1);
+
+        // Connection is one-shot.
+        assert_eq!(signal.connections().len(), 0);
+
+        match Pin::new(&mut future).poll(&mut cx) {
+            Poll::Ready(args) => assert_eq!(args, vec![987i64.to_variant(), arg]),
+            Poll::Pending => panic!("future should be resolved after emission"),
+        }
+    }
+
+    #[itest]
+    fn signal_future_drop_disconnects() {
+        let mut object = RefCounted::new_gd();
+        object.add_user_signal("test_signal".into());
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        let future = signal.to_future();
+        assert_eq!(signal.connections().len(), 1);
+
+        drop(future);
+        assert_eq!(signal.connections().len(), 0);
+    }
+}
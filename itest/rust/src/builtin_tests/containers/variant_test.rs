@@ -9,7 +9,7 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 
 use godot::builtin::{
-    dict, varray, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
+    array, dict, varray, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
 };
 use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
 use godot::classes::{Node, Node2D};
@@ -156,6 +156,42 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::BASIS)
 }
 
+#[itest]
+fn variant_type_name() {
+    assert_eq!(Variant::nil().type_name(), "Nil");
+    assert_eq!(74i32.to_variant().type_name(), "int");
+    assert_eq!(7.5.to_variant().type_name(), "float");
+    assert_eq!(true.to_variant().type_name(), "bool");
+    assert_eq!(gstr("hello").to_variant().type_name(), "String");
+    assert_eq!(
+        StringName::from("hello").to_variant().type_name(),
+        "StringName"
+    );
+    assert_eq!(Vector2::ZERO.to_variant().type_name(), "Vector2");
+    assert_eq!(TEST_BASIS.to_variant().type_name(), "Basis");
+    assert_eq!(varray![1, 2].to_variant().type_name(), "Array");
+    assert_eq!(Dictionary::new().to_variant().type_name(), "Dictionary");
+
+    let node = Node::new_alloc();
+    assert_eq!(node.to_variant().type_name(), "Object");
+    node.free();
+}
+
+#[itest]
+fn variant_is_array_of() {
+    let typed = array![1i64, 2, 3].to_variant();
+    assert!(typed.is_array_of::<i64>());
+    assert!(!typed.is_array_of::<GString>());
+    assert!(!typed.is_array_of::<Variant>());
+
+    let untyped = varray![1, "two"].to_variant();
+    assert!(untyped.is_array_of::<Variant>());
+    assert!(!untyped.is_array_of::<i64>());
+
+    assert!(!7.to_variant().is_array_of::<i64>());
+    assert!(!Variant::nil().is_array_of::<Variant>());
+}
+
 #[itest]
 fn variant_equal() {
     assert_eq!(Variant::nil(), ().to_variant());
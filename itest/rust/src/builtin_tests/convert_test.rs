@@ -5,8 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    dict, varray, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis, Vector2i,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -233,3 +238,277 @@ fn custom_convert_error_from_variant() {
         format!("{:?}", i64::MAX)
     );
 }
+
+#[itest]
+fn hash_map_convert_roundtrip() {
+    let map: HashMap<GString, i64> = HashMap::from([
+        (GString::from("one"), 1),
+        (GString::from("two"), 2),
+        (GString::from("three"), 3),
+    ]);
+
+    let dict = map.to_godot();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get("two"), Some(2.to_variant()));
+
+    let from_godot = HashMap::<GString, i64>::from_godot(dict);
+    assert_eq!(from_godot, map);
+
+    let from_variant = map.to_variant().to::<HashMap<GString, i64>>();
+    assert_eq!(from_variant, map);
+}
+
+#[itest]
+fn hash_map_convert_error() {
+    let wrong_value = dict! {
+        "one": 1,
+        "two": "two",
+    };
+    let err = wrong_value
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("value `two` should fail to convert");
+
+    assert_eq!(
+        format!("{:?}", err.value().unwrap()),
+        format!("{:?}", "two".to_variant())
+    );
+
+    let wrong_key = dict! {
+        "one": 1,
+        2: 2,
+    };
+    wrong_key
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("key `2` should fail to convert");
+}
+
+#[itest]
+fn tuple_convert_roundtrip() {
+    let tuple = (42i64, GString::from("hello"), true);
+
+    let array = tuple.to_godot();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.at(0), 42.to_variant());
+    assert_eq!(array.at(1), "hello".to_variant());
+    assert_eq!(array.at(2), true.to_variant());
+
+    let from_godot = <(i64, GString, bool)>::from_godot(array);
+    assert_eq!(from_godot, tuple);
+
+    let from_variant = tuple.to_variant().to::<(i64, GString, bool)>();
+    assert_eq!(from_variant, tuple);
+}
+
+#[itest]
+fn tuple_convert_error() {
+    let too_short = varray![42, "hello"];
+    let err = too_short
+        .to_variant()
+        .try_to::<(i64, GString, bool)>()
+        .expect_err("array is too short");
+    assert!(err
+        .to_string()
+        .starts_with("expected array of length 3, got length 2"));
+
+    let too_long = varray![42, "hello", true, 7];
+    too_long
+        .to_variant()
+        .try_to::<(i64, GString, bool)>()
+        .expect_err("array is too long");
+
+    let wrong_type = varray![42, "hello", 7.5];
+    let err = wrong_type
+        .to_variant()
+        .try_to::<(i64, GString, bool)>()
+        .expect_err("third element is not a bool");
+    assert_eq!(
+        format!("{:?}", err.value().unwrap()),
+        format!("{:?}", 7.5.to_variant())
+    );
+}
+
+#[itest]
+fn duration_convert() {
+    let duration = Duration::from_millis(1500);
+
+    assert_eq!(duration.to_godot(), 1.5);
+    assert_eq!(duration.to_variant(), 1.5.to_variant());
+    assert_eq!(Duration::from_godot(1.5), duration);
+    assert_eq!(duration.to_variant().to::<Duration>(), duration);
+
+    Duration::try_from_godot(f64::NAN).expect_err("NaN is not a valid duration");
+    Duration::try_from_godot(f64::INFINITY).expect_err("infinity is not a valid duration");
+    Duration::try_from_godot(-1.0).expect_err("negative durations are not supported");
+}
+
+#[itest]
+fn u64_try_to_variant() {
+    let small: u64 = 42;
+    assert_eq!(small.try_to_variant().unwrap(), 42.to_variant());
+
+    let max_repr = i64::MAX as u64;
+    assert_eq!(max_repr.try_to_variant().unwrap(), i64::MAX.to_variant());
+
+    let err = u64::MAX
+        .try_to_variant()
+        .expect_err("u64::MAX is not representable as Variant");
+    assert!(err.value().is_none());
+    assert!(err.to_string().contains("18446744073709551615"));
+
+    // Infallible types always succeed.
+    assert_eq!(
+        GString::from("s").try_to_variant().unwrap(),
+        "s".to_variant()
+    );
+}
+
+#[itest]
+fn u64_try_to_variant_in_containers() {
+    // Containers propagate element errors instead of panicking.
+    let tuple = (1, u64::MAX);
+    let err = tuple
+        .try_to_variant()
+        .expect_err("tuple element u64::MAX is not representable");
+    assert!(err.to_string().contains("18446744073709551615"));
+
+    let tuple = (1, 2u64);
+    assert_eq!(tuple.try_to_variant().unwrap(), varray![1, 2].to_variant());
+
+    let map = HashMap::from([("big", u64::MAX)]);
+    map.try_to_variant()
+        .expect_err("map value u64::MAX is not representable");
+
+    let map = HashMap::from([("small", 3u64)]);
+    assert_eq!(
+        map.try_to_variant().unwrap(),
+        dict! { "small": 3 }.to_variant()
+    );
+}
+
+#[itest]
+fn int128_convert() {
+    let big = u128::MAX;
+    assert_eq!(
+        big.to_godot(),
+        GString::from("340282366920938463463374607431768211455")
+    );
+    assert_eq!(big.to_variant().to::<u128>(), big);
+
+    let beyond_i64 = i64::MAX as u128 + 1;
+    assert_eq!(u128::from_godot(beyond_i64.to_godot()), beyond_i64);
+
+    let negative = i128::MIN;
+    assert_eq!(negative.to_variant().to::<i128>(), negative);
+    assert_eq!(
+        i128::from_godot(GString::from("-9223372036854775809")),
+        i64::MIN as i128 - 1
+    );
+
+    u128::try_from_godot(GString::from("-1")).expect_err("negative value for u128");
+    u128::try_from_godot(GString::from("340282366920938463463374607431768211456"))
+        .expect_err("value beyond u128::MAX");
+    i128::try_from_godot(GString::from("12abc")).expect_err("not a number");
+    i128::try_from_godot(GString::new()).expect_err("empty string");
+}
+
+#[itest]
+fn char_convert() {
+    assert_eq!('A'.to_godot(), 65);
+    assert_eq!(char::from_godot(65), 'A');
+
+    // BMP character.
+    let bmp = 'ä';
+    assert_eq!(bmp.to_variant(), 0xE4.to_variant());
+    assert_eq!(bmp.to_variant().to::<char>(), bmp);
+
+    // Astral plane character.
+    let emoji = '🦀';
+    assert_eq!(emoji.to_godot(), 0x1F980);
+    assert_eq!(emoji.to_variant().to::<char>(), emoji);
+    assert_eq!(
+        GString::from("🦀").as_inner().unicode_at(0),
+        emoji.to_godot()
+    );
+
+    char::try_from_godot(0xD800).expect_err("surrogates are not valid chars");
+    char::try_from_godot(0x110000).expect_err("value is beyond the Unicode range");
+    char::try_from_godot(-1).expect_err("negative values are not valid chars");
+}
+
+#[itest]
+fn range_convert() {
+    let range = -3..7;
+    assert_eq!(range.to_godot(), Vector2i::new(-3, 7));
+    assert_eq!(Range::<i32>::from_godot(Vector2i::new(-3, 7)), range);
+    assert_eq!(range.to_variant().to::<Range<i32>>(), range);
+
+    let inclusive = -3..=7;
+    assert_eq!(inclusive.to_godot(), Vector2i::new(-3, 7));
+    assert_eq!(
+        RangeInclusive::<i32>::from_godot(Vector2i::new(-3, 7)),
+        inclusive
+    );
+    assert_eq!(
+        inclusive.to_variant().to::<RangeInclusive<i32>>(),
+        inclusive
+    );
+}
+
+#[itest]
+#[allow(clippy::reversed_empty_ranges)]
+fn range_convert_empty_and_reversed() {
+    let empty = 4..4;
+    let converted = empty.to_variant().to::<Range<i32>>();
+    assert_eq!(converted, empty);
+    assert!(converted.is_empty());
+
+    // Reversed bounds are preserved as-is, not normalized.
+    let reversed = 7..-3;
+    let converted = reversed.to_variant().to::<Range<i32>>();
+    assert_eq!(converted, reversed);
+    assert!(converted.is_empty());
+
+    let reversed_inclusive = 7..=-3;
+    let converted = reversed_inclusive.to_variant().to::<RangeInclusive<i32>>();
+    assert_eq!(converted, reversed_inclusive);
+    assert!(converted.is_empty());
+}
+
+#[itest]
+fn path_convert() {
+    let path = PathBuf::from("assets/textures/icon.png");
+
+    assert_eq!(path.to_godot(), GString::from("assets/textures/icon.png"));
+    assert_eq!(PathBuf::from_godot(path.to_godot()), path);
+    assert_eq!(path.to_variant().to::<PathBuf>(), path);
+    assert_eq!(
+        path.try_to_variant().unwrap(),
+        "assets/textures/icon.png".to_variant()
+    );
+
+    let borrowed: &Path = Path::new("res://scenes/main.tscn");
+    assert_eq!(borrowed.to_variant(), "res://scenes/main.tscn".to_variant());
+    assert_eq!(
+        borrowed.to_variant().to::<PathBuf>(),
+        borrowed.to_path_buf()
+    );
+}
+
+#[cfg(unix)]
+#[itest]
+fn path_convert_non_utf8() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = Path::new(OsStr::from_bytes(b"invalid\xFF.txt"));
+
+    let err = path
+        .try_to_variant()
+        .expect_err("non-UTF-8 path is not representable");
+    assert!(err.value().is_none());
+
+    // Infallible conversion is lossy.
+    assert_eq!(path.to_godot(), GString::from("invalid\u{FFFD}.txt"));
+}
@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+
+use godot::builtin::inner::InnerAabb;
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Aabb, Vector3, Vector3Axis};
+
+#[itest]
+fn aabb_inner_equivalence() {
+    let aabbs = [
+        Aabb::new(Vector3::ZERO, Vector3::new(1.0, 2.0, 3.0)),
+        Aabb::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(0.5, 0.75, 0.25)),
+        Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 1.0, 1.0)),
+        Aabb::new(Vector3::new(-5.0, -5.0, -5.0), Vector3::new(1.0, 1.0, 2.0)),
+    ];
+
+    for aabb in aabbs {
+        let inner = InnerAabb::from_outer(&aabb);
+
+        assert_eq_approx!(aabb.center(), inner.get_center());
+        assert_eq_approx!(aabb.longest_axis().unwrap(), inner.get_longest_axis());
+
+        for other in aabbs {
+            assert_eq!(aabb.encloses(other), inner.encloses(other));
+            // Godot's `intersects()` excludes borders; some fixtures only touch each other.
+            assert_eq!(
+                aabb.intersects_exclude_borders(other),
+                inner.intersects(other)
+            );
+            assert_eq_approx!(aabb.merge(other), inner.merge(other));
+        }
+    }
+}
+
+#[itest]
+fn aabb_containment() {
+    let outer = Aabb::new(Vector3::ZERO, Vector3::new(4.0, 4.0, 4.0));
+    let inside = Aabb::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 2.0, 2.0));
+    let overlapping = Aabb::new(Vector3::new(3.0, 3.0, 3.0), Vector3::new(2.0, 2.0, 2.0));
+    let disjoint = Aabb::new(Vector3::new(10.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+
+    assert!(outer.encloses(inside));
+    assert!(outer.encloses(outer));
+    assert!(!inside.encloses(outer));
+    assert!(!outer.encloses(overlapping));
+
+    assert!(outer.intersects(inside));
+    assert!(outer.intersects(overlapping));
+    assert!(!outer.intersects(disjoint));
+
+    let merged = outer.merge(disjoint);
+    assert_eq!(
+        merged,
+        Aabb::new(Vector3::ZERO, Vector3::new(11.0, 4.0, 4.0))
+    );
+    assert!(merged.encloses(outer));
+    assert!(merged.encloses(disjoint));
+
+    assert_eq!(inside.center(), Vector3::new(2.0, 2.0, 2.0));
+}
+
+#[itest]
+fn aabb_intersects_separated_along_z() {
+    let a = Aabb::new(Vector3::ZERO, Vector3::new(1.0, 1.0, 1.0));
+    let b = Aabb::new(Vector3::new(0.0, 0.0, 5.0), Vector3::new(1.0, 1.0, 1.0));
+
+    // Overlapping in x and y, but not in z -- in both orders.
+    assert!(!a.intersects(b));
+    assert!(!b.intersects(a));
+    assert!(!a.intersects_exclude_borders(b));
+
+    // Touching faces along z.
+    let touching = Aabb::new(Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 1.0));
+    assert!(a.intersects(touching));
+    assert!(!a.intersects_exclude_borders(touching));
+}
+
+#[itest]
+fn aabb_longest_axis() {
+    let aabb = Aabb::new(Vector3::ZERO, Vector3::new(1.0, 5.0, 2.0));
+    assert_eq!(aabb.longest_axis_index(), Some(Vector3Axis::Y));
+    assert_eq!(aabb.longest_axis(), Some(Vector3::UP));
+    assert_eq!(aabb.longest_axis_size(), 5.0);
+
+    let aabb = Aabb::new(Vector3::ZERO, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(aabb.longest_axis_index(), Some(Vector3Axis::Z));
+    assert_eq!(aabb.longest_axis(), Some(Vector3::BACK));
+
+    // A cube has no unique longest axis.
+    let cube = Aabb::new(Vector3::ZERO, Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(cube.longest_axis_index(), None);
+    assert_eq!(cube.longest_axis_size(), 2.0);
+}
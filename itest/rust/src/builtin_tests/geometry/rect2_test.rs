@@ -91,3 +91,41 @@ fn rect2_inner_equivalence() {
         }
     }
 }
+
+#[itest]
+fn rect2_overlap_touch_disjoint() {
+    let a = Rect2::from_components(0.0, 0.0, 4.0, 4.0);
+    let overlapping = Rect2::from_components(2.0, 2.0, 4.0, 4.0);
+    let touching = Rect2::from_components(4.0, 0.0, 2.0, 4.0);
+    let disjoint = Rect2::from_components(10.0, 10.0, 1.0, 1.0);
+    let inside = Rect2::from_components(1.0, 1.0, 2.0, 2.0);
+
+    assert!(a.intersects(overlapping));
+    assert!(a.intersects_exclude_borders(overlapping));
+    assert_eq!(
+        a.intersection(overlapping),
+        Some(Rect2::from_components(2.0, 2.0, 2.0, 2.0))
+    );
+    assert_eq!(
+        a.merge(overlapping),
+        Rect2::from_components(0.0, 0.0, 6.0, 6.0)
+    );
+
+    // Touching rects share an edge: they intersect only when borders are included.
+    assert!(a.intersects(touching));
+    assert!(!a.intersects_exclude_borders(touching));
+    assert!(!a.intersection(touching).unwrap().has_area());
+
+    assert!(!a.intersects(disjoint));
+    assert!(!a.intersects_exclude_borders(disjoint));
+    assert_eq!(a.intersection(disjoint), None);
+    assert_eq!(
+        a.merge(disjoint),
+        Rect2::from_components(0.0, 0.0, 11.0, 11.0)
+    );
+
+    assert!(a.encloses(inside));
+    assert!(a.encloses(a));
+    assert!(!a.encloses(overlapping));
+    assert!(!inside.encloses(a));
+}
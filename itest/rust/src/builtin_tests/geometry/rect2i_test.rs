@@ -89,3 +89,33 @@ fn rect2i_equiv_unary() {
         }
     }
 }
+
+#[itest]
+fn rect2i_overlap_touch_disjoint() {
+    let a = Rect2i::from_components(0, 0, 4, 4);
+    let overlapping = Rect2i::from_components(2, 2, 4, 4);
+    let touching = Rect2i::from_components(4, 0, 2, 4);
+    let disjoint = Rect2i::from_components(10, 10, 1, 1);
+    let inside = Rect2i::from_components(1, 1, 2, 2);
+
+    assert!(a.intersects(overlapping));
+    assert_eq!(
+        a.intersection(overlapping),
+        Some(Rect2i::from_components(2, 2, 2, 2))
+    );
+    assert_eq!(a.merge(overlapping), Rect2i::from_components(0, 0, 6, 6));
+
+    // Unlike Rect2, integer rects that only share a border do not intersect.
+    assert!(!a.intersects(touching));
+    assert_eq!(a.intersection(touching), None);
+    assert_eq!(a.merge(touching), Rect2i::from_components(0, 0, 6, 4));
+
+    assert!(!a.intersects(disjoint));
+    assert_eq!(a.intersection(disjoint), None);
+    assert_eq!(a.merge(disjoint), Rect2i::from_components(0, 0, 11, 11));
+
+    assert!(a.encloses(inside));
+    assert!(a.encloses(a));
+    assert!(!a.encloses(overlapping));
+    assert!(!inside.encloses(a));
+}
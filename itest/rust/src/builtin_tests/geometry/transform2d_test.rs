@@ -8,6 +8,7 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform2D;
+use godot::builtin::real_consts::FRAC_PI_2;
 use godot::builtin::{real, RealConv, Rect2, Transform2D, VariantOperator, Vector2};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
@@ -94,3 +95,20 @@ fn transform2d_xform_equiv() {
         "operator: Transform2D * Rect2 (2)"
     );
 }
+
+#[itest]
+fn transform2d_interpolate_with() {
+    let to = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::new(2.0, 4.0));
+
+    let mid = Transform2D::IDENTITY.interpolate_with(&to, 0.5);
+    assert_eq_approx!(mid.origin, Vector2::new(1.0, 2.0));
+    assert_eq_approx!(mid.rotation(), FRAC_PI_2 / 2.0);
+    assert_eq_approx!(mid.scale(), Vector2::ONE);
+
+    // Endpoints are reproduced exactly.
+    assert_eq_approx!(
+        Transform2D::IDENTITY.interpolate_with(&to, 0.0),
+        Transform2D::IDENTITY
+    );
+    assert_eq_approx!(Transform2D::IDENTITY.interpolate_with(&to, 1.0), to);
+}
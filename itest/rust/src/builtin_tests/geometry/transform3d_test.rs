@@ -8,6 +8,7 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform3D;
+use godot::builtin::real_consts::FRAC_PI_2;
 use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
@@ -82,3 +83,25 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_interpolate_with() {
+    let to = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2),
+        Vector3::new(2.0, 4.0, 6.0),
+    );
+
+    let mid = Transform3D::IDENTITY.interpolate_with(&to, 0.5);
+    let expected = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2 / 2.0),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+    assert_eq_approx!(mid, expected);
+
+    // Endpoints are reproduced exactly.
+    assert_eq_approx!(
+        Transform3D::IDENTITY.interpolate_with(&to, 0.0),
+        Transform3D::IDENTITY
+    );
+    assert_eq_approx!(Transform3D::IDENTITY.interpolate_with(&to, 1.0), to);
+}
@@ -102,11 +102,30 @@ fn sign() {
     assert_eq!(b.sign(), b.as_inner().sign());
 }
 
-// TODO: implement snapped for integer vectors
-// #[itest]
-// fn snapped() {
-//     let a = Vector2i::new(12, 34);
-//     let b = Vector2i::new(5, -5);
-
-//     assert_eq!(a.snapped(b), a.as_inner().snapped(b));
-// }
+#[itest]
+fn snapped() {
+    let a = Vector2i::new(12, 34);
+    let b = Vector2i::new(5, -5);
+
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
+
+#[itest]
+fn snapped_negative() {
+    let a = Vector2i::new(-12, -15);
+    let b = Vector2i::new(5, 10);
+
+    assert_eq!(a.snapped(b), Vector2i::new(-10, -10));
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+    assert_eq!(a.snapped(-b), a.as_inner().snapped(-b));
+}
+
+#[itest]
+fn snapped_zero_step() {
+    let a = Vector2i::new(-12, 34);
+    let b = Vector2i::new(0, 5);
+
+    assert_eq!(a.snapped(b), Vector2i::new(-12, 35));
+    assert_eq!(a.snapped(Vector2i::ZERO), a);
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
@@ -11,7 +11,7 @@ use godot::builtin::{
     inner::InnerVector3,
     math::{assert_eq_approx, ApproxEq},
     real,
-    real_consts::PI,
+    real_consts::{PI, SQRT_2},
     Vector3, Vector3Axis,
 };
 
@@ -423,6 +423,27 @@ fn slerp() {
     assert_eq_approx!(a.slerp(b, c as real), a.as_inner().slerp(b, c));
 }
 
+#[itest]
+fn slerp_zero_length() {
+    let a = Vector3::ZERO;
+    let b = Vector3::new(-7.8, 9.10, -11.12);
+
+    assert_eq!(a.slerp(b, 0.5), a.lerp(b, 0.5));
+    assert_eq!(b.slerp(a, 0.5), b.lerp(a, 0.5));
+    assert_eq_approx!(a.slerp(b, 0.5), a.as_inner().slerp(b, 0.5));
+}
+
+#[itest]
+fn slerp_quarter_turn() {
+    let a = Vector3::RIGHT;
+    let b = Vector3::FORWARD * 3.0;
+
+    let half = SQRT_2;
+    assert_eq_approx!(a.slerp(b, 0.5), Vector3::new(half, 0.0, -half));
+    assert_eq_approx!(a.slerp(b, 1.0), b);
+    assert_eq_approx!(a.slerp(b, 0.5), a.as_inner().slerp(b, 0.5));
+}
+
 #[itest]
 fn slide() {
     let a = Vector3::new(1.2, -3.4, 5.6);
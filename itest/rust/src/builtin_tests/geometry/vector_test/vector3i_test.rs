@@ -99,11 +99,28 @@ fn sign() {
     assert_eq!(b.sign(), b.as_inner().sign());
 }
 
-// TODO: implement snapped for integer vectors
-// #[itest]
-// fn snapped() {
-//     let a = Vector3i::new(12, 34, 56);
-//     let b = Vector3i::new(5, -5, 6);
-
-//     assert_eq!(a.snapped(b), a.as_inner().snapped(b));
-// }
+#[itest]
+fn snapped() {
+    let a = Vector3i::new(12, 34, 56);
+    let b = Vector3i::new(5, -5, 6);
+
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
+
+#[itest]
+fn snapped_negative() {
+    let a = Vector3i::new(-12, -15, -56);
+    let b = Vector3i::new(5, 10, -6);
+
+    assert_eq!(a.snapped(b), Vector3i::new(-10, -10, -54));
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
+
+#[itest]
+fn snapped_zero_step() {
+    let a = Vector3i::new(-12, 34, 56);
+    let b = Vector3i::new(0, 5, 0);
+
+    assert_eq!(a.snapped(b), Vector3i::new(-12, 35, 56));
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
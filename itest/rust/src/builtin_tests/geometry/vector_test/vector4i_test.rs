@@ -103,11 +103,28 @@ fn sign() {
     assert_eq!(b.sign(), b.as_inner().sign());
 }
 
-// TODO: implement snapped for integer vectors
-// #[itest]
-// fn snapped() {
-//     let a = Vector4i::new(12, 34, 56, 78);
-//     let b = Vector4i::new(5, -5, 6, -6);
-
-//     assert_eq!(a.snapped(b), a.as_inner().snapped(b));
-// }
+#[itest]
+fn snapped() {
+    let a = Vector4i::new(12, 34, 56, 78);
+    let b = Vector4i::new(5, -5, 6, -6);
+
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
+
+#[itest]
+fn snapped_negative() {
+    let a = Vector4i::new(-12, -15, -56, -78);
+    let b = Vector4i::new(5, 10, 6, -6);
+
+    assert_eq!(a.snapped(b), Vector4i::new(-10, -10, -54, -78));
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
+
+#[itest]
+fn snapped_zero_step() {
+    let a = Vector4i::new(-12, 34, 56, 78);
+    let b = Vector4i::new(0, 5, 0, 10);
+
+    assert_eq!(a.snapped(b), Vector4i::new(-12, 35, 56, 80));
+    assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+}
@@ -6,7 +6,11 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{array, Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i};
+use godot::builtin::{
+    array, dict, varray, Array, Color, ColorHsv, Dictionary, GString, NodePath, StringName,
+    Variant, VariantArray, Vector2, Vector2i,
+};
+use godot::meta::ToGodot;
 use serde::{Deserialize, Serialize};
 
 fn serde_roundtrip<T>(value: &T, expected_json: &str)
@@ -92,3 +96,81 @@ fn color_hsv_serde() {
     let expected_json = r#"{"h":0.0,"s":0.0,"v":0.0,"a":1.0}"#;
     serde_roundtrip(&color, expected_json);
 }
+
+#[itest]
+fn serde_variant_primitives() {
+    serde_roundtrip(&Variant::nil(), "null");
+    serde_roundtrip(&true.to_variant(), "true");
+    serde_roundtrip(&(-42i64).to_variant(), "-42");
+    serde_roundtrip(&1.5f64.to_variant(), "1.5");
+    serde_roundtrip(&"text".to_variant(), r#""text""#);
+}
+
+#[itest]
+fn serde_variant_array() {
+    let value: VariantArray = varray![1, "two", 3.5, Variant::nil()];
+    let expected_json = r#"[1,"two",3.5,null]"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_dictionary_nested() {
+    let value = dict! {
+        "name": "player",
+        "level": 7,
+        "stats": dict! { "hp": 10.5, "alive": true },
+        "inventory": varray!["sword", varray![1, 2], Dictionary::new()],
+    };
+    let expected_json = r#"{"name":"player","level":7,"stats":{"hp":10.5,"alive":true},"inventory":["sword",[1,2],{}]}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_dictionary_empty() {
+    serde_roundtrip(&Dictionary::new(), "{}");
+}
+
+#[itest]
+fn serde_dictionary_string_name_key() {
+    let value = dict! { (StringName::from("key")): 1 };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"key":1}"#);
+
+    // Keys come back as GString.
+    let back: Dictionary = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.get("key"), Some(1.to_variant()));
+}
+
+#[itest]
+fn serde_dictionary_non_string_key() {
+    let value = dict! { 1: "one" };
+    let err = serde_json::to_string(&value).expect_err("int keys are not serializable");
+    assert!(err.to_string().contains("only String and StringName keys"));
+
+    let nested = dict! { "outer": dict! { (Vector2::ZERO): true } };
+    assert!(serde_json::to_string(&nested).is_err());
+}
+
+#[itest]
+fn serde_variant_unsupported_type() {
+    let value = Vector2::new(1.0, 2.0).to_variant();
+    let err = serde_json::to_string(&value).expect_err("Vector2 variant is not serializable");
+    assert!(err.to_string().contains("Vector2"));
+}
+
+#[itest]
+fn serde_dictionary_self_reference() {
+    let mut dict = dict! { "a": 1 };
+    dict.set("self", dict.clone());
+
+    let err = serde_json::to_string(&dict).expect_err("cyclic dictionary is not serializable");
+    assert!(err.to_string().contains("contain itself"), "{err}");
+
+    // Depth tracking is reset after the error, so unrelated values still serialize.
+    serde_roundtrip(&dict! { "b": varray![2] }, r#"{"b":[2]}"#);
+
+    // Break the reference cycle, otherwise the dictionary is leaked.
+    dict.clear();
+}
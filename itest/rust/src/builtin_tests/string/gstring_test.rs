@@ -5,10 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::{dict, varray, Dictionary, GString, PackedStringArray};
+use godot::meta::ToGodot;
 
 // TODO use tests from godot-rust/gdnative
 
@@ -96,6 +98,26 @@ fn string_chars() {
     }
 }
 
+#[itest]
+#[cfg(since_api = "4.1")]
+fn string_chars_emoji() {
+    let gstring = GString::from("a😎b🦀");
+
+    let chars: Vec<char> = gstring.chars().iter().copied().collect();
+    assert_eq!(chars, vec!['a', '😎', 'b', '🦀']);
+
+    // Length counts Unicode scalar values, not UTF-8 bytes.
+    assert_eq!(gstring.len(), 4);
+    assert_eq!(gstring.to_string().len(), 10);
+}
+
+#[itest]
+fn string_display() {
+    let gstring = GString::from("emoji time: 😎");
+    assert_eq!(format!("{gstring}"), "emoji time: 😎");
+    assert_eq!(format!("[{}]", GString::new()), "[]");
+}
+
 #[itest]
 fn string_hash() {
     let set: HashSet<GString> = [
@@ -129,3 +151,85 @@ fn string_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_format_positional() {
+    let template = GString::from("{0} + {1} = {2}");
+    let formatted = template.format(&varray![1, 2, "three"].to_variant());
+
+    assert_eq!(formatted, GString::from("1 + 2 = three"));
+}
+
+#[itest]
+fn string_format_named() {
+    let template = GString::from("{name} is {age} years old, {name}!");
+    let formatted = template.format(&dict! { "name": "Alice", "age": 7 }.to_variant());
+
+    assert_eq!(formatted, GString::from("Alice is 7 years old, Alice!"));
+
+    // Placeholders without a matching key are left as-is.
+    let formatted = GString::from("{missing}").format(&Dictionary::new().to_variant());
+    assert_eq!(formatted, GString::from("{missing}"));
+}
+
+#[itest]
+fn string_split() {
+    let string = GString::from("a,b,,c");
+
+    let to_vec = |parts: &[&str]| parts.iter().copied().map(GString::from).collect::<Vec<_>>();
+
+    let with_empty = string.split(",", true);
+    assert_eq!(with_empty.as_slice(), to_vec(&["a", "b", "", "c"]));
+
+    let without_empty = string.split(",", false);
+    assert_eq!(without_empty.as_slice(), to_vec(&["a", "b", "c"]));
+}
+
+#[itest]
+fn string_join() {
+    let string = GString::from("a,b,,c");
+
+    let parts = string.split(",", true);
+    assert_eq!(GString::join(&parts, ","), string);
+
+    let parts = string.split(",", false);
+    assert_eq!(GString::join(&parts, " | "), GString::from("a | b | c"));
+
+    assert_eq!(
+        GString::join(&PackedStringArray::new(), ","),
+        GString::new()
+    );
+}
+
+#[itest]
+fn string_nocasecmp_to() {
+    let a = GString::from("Apple");
+
+    assert_eq!(a.nocasecmp_to(&GString::from("apple")), Ordering::Equal);
+    assert_eq!(a.nocasecmp_to(&GString::from("BANANA")), Ordering::Less);
+    assert_eq!(GString::from("cherry").nocasecmp_to(&a), Ordering::Greater);
+}
+
+#[itest]
+fn string_natural_nocasecmp_to() {
+    let mut files: Vec<GString> = ["file10", "File2", "file1"]
+        .into_iter()
+        .map(GString::from)
+        .collect();
+
+    files.sort_by(|a, b| a.natural_nocasecmp_to(b));
+    assert_eq!(
+        files,
+        [
+            GString::from("file1"),
+            GString::from("File2"),
+            GString::from("file10")
+        ]
+    );
+
+    // Plain lexicographic comparison would put "file10" before "file2".
+    let file2 = GString::from("file2");
+    let file10 = GString::from("file10");
+    assert_eq!(file10.nocasecmp_to(&file2), Ordering::Less);
+    assert_eq!(file10.natural_nocasecmp_to(&file2), Ordering::Greater);
+}
@@ -83,3 +83,50 @@ fn node_path_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn node_path_push() {
+    let name_count = |path: &NodePath| path.as_inner().get_name_count();
+
+    let mut path = NodePath::default();
+    assert_eq!(name_count(&path), 0);
+
+    path.push("Parent");
+    assert_eq!(path, NodePath::from("Parent"));
+    assert_eq!(name_count(&path), 1);
+
+    path.push("Child");
+    assert_eq!(path, NodePath::from("Parent/Child"));
+    assert_eq!(name_count(&path), 2);
+
+    // Multiple names at once.
+    path.push("Grandchild/Leaf");
+    assert_eq!(path, NodePath::from("Parent/Child/Grandchild/Leaf"));
+    assert_eq!(name_count(&path), 4);
+
+    // Absolute paths and subnames are preserved.
+    let mut path = NodePath::from("/root:position:x");
+    path.push("Main");
+    assert_eq!(path, NodePath::from("/root/Main:position:x"));
+    assert_eq!(name_count(&path), 2);
+    assert!(path.as_inner().is_absolute());
+
+    // Bytes after a null byte are ignored, like in other conversions.
+    let mut path = NodePath::from("Parent");
+    path.push("Child\0 ignored");
+    assert_eq!(path, NodePath::from("Parent/Child"));
+}
+
+#[itest]
+fn node_path_add() {
+    let base = NodePath::from("Parent");
+
+    let path = base.clone() + "Child";
+    assert_eq!(path, NodePath::from("Parent/Child"));
+    assert_eq!(base, NodePath::from("Parent"));
+
+    let mut path = path;
+    path += "Grandchild";
+    assert_eq!(path, NodePath::from("Parent/Child/Grandchild"));
+    assert_eq!(path.as_inner().get_name_count(), 3);
+}
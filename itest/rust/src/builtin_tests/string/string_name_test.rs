@@ -125,6 +125,21 @@ fn string_name_is_empty() {
     assert!(empty.is_empty());
 }
 
+#[itest]
+fn string_name_substring_queries() {
+    let name = StringName::from("on_body_entered");
+
+    assert!(name.begins_with("on_"));
+    assert!(name.begins_with(""));
+    assert!(!name.begins_with("body"));
+
+    assert!(name.ends_with("_entered"));
+    assert!(!name.ends_with("on_"));
+
+    assert!(name.contains("body"));
+    assert!(!name.contains("exited"));
+}
+
 #[itest]
 #[cfg(since_api = "4.2")]
 fn string_name_from_cstr() {
@@ -162,3 +177,19 @@ fn string_name_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_name_intern() {
+    let a = StringName::intern("interned_name");
+    let b = StringName::intern("interned_name");
+    assert_eq!(a, b);
+    assert_eq!(a, StringName::from("interned_name"));
+
+    // A StringName is a single pointer to Godot's internal data; both clones must share it.
+    let data_ptr = |name: &StringName| unsafe { *name.string_sys().cast::<*const u8>() };
+    assert_eq!(data_ptr(&a), data_ptr(&b));
+
+    let other = StringName::intern("other_interned_name");
+    assert_ne!(a, other);
+    assert_ne!(data_ptr(&a), data_ptr(&other));
+}
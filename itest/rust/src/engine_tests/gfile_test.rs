@@ -140,6 +140,39 @@ fn seek_trait_works() {
     remove_test_file();
 }
 
+#[itest]
+fn seek_trait_generic_consumer() {
+    // Typical signature of third-party readers (archives, decoders, ...).
+    fn read_tail<R: Read + Seek>(reader: &mut R, count: i64) -> std::io::Result<Vec<u8>> {
+        reader.seek(SeekFrom::End(-count))?;
+        let mut tail = Vec::new();
+        reader.read_to_end(&mut tail)?;
+        Ok(tail)
+    }
+
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
+    file.write_all(b"0123456789").unwrap();
+
+    assert_eq!(read_tail(&mut file, 3).unwrap(), b"789");
+    assert_eq!(file.stream_position().unwrap(), 10);
+
+    file.seek(SeekFrom::Start(2)).unwrap();
+    file.seek(SeekFrom::Current(3)).unwrap();
+    assert_eq!(file.stream_position().unwrap(), 5);
+    assert_eq!(file.read_u8().unwrap(), b'5');
+
+    // Seeking before the beginning fails and leaves the cursor untouched.
+    assert!(file.seek(SeekFrom::End(-11)).is_err());
+    assert!(file.seek(SeekFrom::Current(-7)).is_err());
+    assert_eq!(file.stream_position().unwrap(), 6);
+
+    file.seek(SeekFrom::End(-10)).unwrap();
+    assert_eq!(file.read_u8().unwrap(), b'0');
+
+    drop(file);
+    remove_test_file();
+}
+
 #[itest]
 fn bufread_trait_works() {
     let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
@@ -160,3 +193,56 @@ fn bufread_trait_works() {
     drop(file);
     remove_test_file();
 }
+
+#[itest]
+fn read_string_line_works() {
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
+
+    // Last line without trailing newline; Windows line ending in between; empty line.
+    file.write_gstring("First line\nSecond line\r\n\nLast line")
+        .expect("couldn't write to file");
+    file.rewind().unwrap();
+
+    assert_eq!(
+        file.read_string_line().unwrap().as_deref(),
+        Some("First line")
+    );
+    assert_eq!(
+        file.read_string_line().unwrap().as_deref(),
+        Some("Second line")
+    );
+    assert_eq!(file.read_string_line().unwrap().as_deref(), Some(""));
+    assert_eq!(
+        file.read_string_line().unwrap().as_deref(),
+        Some("Last line")
+    );
+    assert_eq!(file.read_string_line().unwrap(), None);
+    assert_eq!(file.read_string_line().unwrap(), None);
+
+    drop(file);
+    remove_test_file();
+}
+
+#[itest]
+fn lines_iterator_works() {
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
+
+    file.write_gstring("skipped\nalpha\nbeta\ngamma")
+        .expect("couldn't write to file");
+    file.rewind().unwrap();
+
+    // Iteration starts at the current cursor position.
+    assert_eq!(file.read_string_line().unwrap().as_deref(), Some("skipped"));
+
+    let lines: Vec<String> = file
+        .by_ref()
+        .lines()
+        .collect::<std::io::Result<_>>()
+        .expect("couldn't read lines");
+    assert_eq!(lines, vec!["alpha", "beta", "gamma"]);
+
+    assert!(file.read_string_line().unwrap().is_none());
+
+    drop(file);
+    remove_test_file();
+}
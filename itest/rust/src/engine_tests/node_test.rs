@@ -51,6 +51,28 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_add_child_ex_builder() {
+    let mut parent = Node::new_alloc();
+
+    // Default parameters: unnamed children get an auto-generated, non-readable name.
+    let child = Node3D::new_alloc();
+    parent.add_child(child.clone().upcast());
+    assert!(child.get_name().to_string().starts_with('@'));
+
+    // Builder overriding one default parameter.
+    let readable_child = Node3D::new_alloc();
+    parent
+        .add_child_ex(readable_child.clone().upcast())
+        .force_readable_name(true)
+        .done();
+
+    assert_eq!(readable_child.get_parent(), Some(parent.clone()));
+    assert!(!readable_child.get_name().to_string().starts_with('@'));
+
+    parent.free();
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
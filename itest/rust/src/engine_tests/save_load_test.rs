@@ -5,9 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::classes::resource_saver::SaverFlags;
+use godot::classes::Texture2D;
+use godot::meta::error::LoadErrorKind;
 use godot::obj::NewGd;
 use godot::register::GodotClass;
-use godot::tools::{load, save, try_load, try_save};
+use godot::tools::{load, save, try_load, try_save, try_save_with_flags};
 
 use crate::framework::itest;
 
@@ -69,3 +72,94 @@ fn load_test() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+#[itest]
+fn save_with_flags_test() {
+    let file_name = "test_compressed_resource.res";
+    let res_path = format!("res://{file_name}");
+
+    let mut resource = SavedGame::new_gd();
+    resource.bind_mut().set_level(77);
+
+    let res = try_save_with_flags(resource, &res_path, SaverFlags::COMPRESS);
+    assert!(res.is_ok());
+
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    let bytes = std::fs::read(godot_path.join(file_name)).expect("compressed file is written");
+    assert_eq!(&bytes[..4], b"RSCC", "binary resource is compressed");
+
+    let loaded = load::<SavedGame>(&res_path);
+    assert_eq!(loaded.bind().get_level(), 77);
+
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn load_error_not_found() {
+    let err = try_load::<SavedGame>("res://no_such_file.tres").expect_err("file does not exist");
+    assert_eq!(err.load_error_kind(), Some(&LoadErrorKind::NotFound));
+}
+
+#[itest]
+fn load_error_load_failed() {
+    let file_name = "test_corrupt_resource.tres";
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    std::fs::write(
+        godot_path.join(file_name),
+        "this is not a valid resource file",
+    )
+    .expect("couldn't write test file");
+
+    let err = try_load::<SavedGame>(format!("res://{file_name}")).expect_err("file is corrupt");
+    assert_eq!(err.load_error_kind(), Some(&LoadErrorKind::LoadFailed));
+
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn load_error_wrong_type() {
+    let file_name = "test_wrong_type_resource.tres";
+    let res_path = format!("res://{file_name}");
+    save(SavedGame::new_gd(), &res_path);
+
+    let err = try_load::<Texture2D>(&res_path).expect_err("SavedGame is not a Texture2D");
+    assert_eq!(
+        err.load_error_kind(),
+        Some(&LoadErrorKind::WrongType {
+            actual_class: "SavedGame".to_string()
+        })
+    );
+    assert!(err.to_string().contains("SavedGame"));
+
+    remove_test_file(file_name);
+}
+
+#[cfg(feature = "serde")]
+#[itest]
+fn serde_resource_path_test() {
+    use godot::obj::Gd;
+
+    let file_name = "test_serde_resource.tres";
+    let res_path = format!("res://{file_name}");
+
+    let mut resource = SavedGame::new_gd();
+    resource.bind_mut().set_level(42);
+    save(resource.clone(), &res_path);
+
+    // Resources not backed by their own file have no path to serialize.
+    let err = serde_json::to_string(&resource).expect_err("unsaved resource has no path");
+    assert!(err.to_string().contains("cannot be serialized"));
+
+    let loaded = load::<SavedGame>(&res_path);
+    let json = serde_json::to_string(&loaded).unwrap();
+    assert_eq!(json, format!("\"{res_path}\""));
+
+    let back: Gd<SavedGame> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.bind().get_level(), 42);
+    assert_eq!(back, loaded, "cached resource is returned");
+
+    let missing = serde_json::from_str::<Gd<SavedGame>>(r#""res://no_such_file.tres""#);
+    assert!(missing.is_err());
+
+    remove_test_file(file_name);
+}
@@ -24,6 +24,15 @@ fn base_instance_id() {
     obj.free();
 }
 
+#[itest]
+fn base_in_init_defaults() {
+    let obj = BaseInitDefaults::new_alloc();
+
+    assert_eq!(obj.bind().base_id, obj.instance_id());
+    assert_eq!(obj.bind().base_class, GString::from("Node2D"));
+    obj.free();
+}
+
 #[itest]
 fn base_access_unbound() {
     let mut obj = Based::new_alloc();
@@ -149,6 +158,17 @@ fn base_smuggling() {
     });
 }
 
+#[itest]
+fn base_try_to_gd() {
+    let (obj, extracted_base) = create_object_with_extracted_base();
+
+    let base_gd = extracted_base.try_to_gd().expect("base alive");
+    assert_eq!(base_gd.instance_id(), obj.instance_id());
+
+    obj.free();
+    assert!(extracted_base.try_to_gd().is_none());
+}
+
 // While base swapping isn't an encouraged workflow, it can also be regarded as a quicker way to swap all individual properties of two base
 // objects -- which is also allowed. It's also similar to slicing in C++. So this is a Ship-of-Theseus problem, and we don't install ergonomic
 // obstacles to prevent it. Here, we test that results are expected and safe.
@@ -187,6 +207,22 @@ fn create_object_with_extracted_base() -> (Gd<Baseless>, Base<Node2D>) {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init, base = Node2D)]
+struct BaseInitDefaults {
+    #[init(val_fn = base_instance_id_of)]
+    base_id: InstanceId,
+
+    #[init(default = base.to_gd().get_class())]
+    base_class: GString,
+
+    base: Base<Node2D>,
+}
+
+fn base_instance_id_of(base: &Base<Node2D>) -> InstanceId {
+    base.to_gd().instance_id()
+}
+
 use renamed_bases::Based;
 mod renamed_bases {
     use super::{GodotClass, Node2D};
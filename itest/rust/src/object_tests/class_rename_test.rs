@@ -24,6 +24,14 @@ pub mod rename {
     pub struct RepeatMe {}
 }
 
+pub mod rename_literal {
+    use super::*;
+
+    #[derive(GodotClass)]
+    #[class(rename = "RepeatLiteral", no_init)]
+    pub struct RepeatMe {}
+}
+
 #[itest]
 fn renaming_changes_the_name() {
     assert_ne!(
@@ -33,3 +41,12 @@ fn renaming_changes_the_name() {
     assert_eq!(dont_rename::RepeatMe::class_name().as_str(), "RepeatMe");
     assert_eq!(rename::RepeatMe::class_name().as_str(), "NoRepeat");
 }
+
+#[itest]
+fn renaming_with_string_literal() {
+    let class_name = rename_literal::RepeatMe::class_name();
+    assert_eq!(class_name.as_str(), "RepeatLiteral");
+
+    let class_db = godot::classes::ClassDb::singleton();
+    assert!(class_db.class_exists(class_name.to_string_name()));
+}
@@ -6,10 +6,12 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::varray;
+use godot::builtin::{varray, VariantOperator};
 use godot::classes::input::CursorShape;
 use godot::classes::mesh::PrimitiveType;
+use godot::classes::object::ConnectFlags;
 use godot::classes::{time, ArrayMesh};
+use godot::global::Key;
 use std::collections::HashSet;
 
 #[itest]
@@ -34,6 +36,48 @@ fn enum_ords_correct() {
     assert_eq!(CursorShape::CURSOR_HELP.ord(), 16);
 }
 
+#[itest]
+fn enum_all() {
+    let all = CursorShape::all();
+
+    assert_eq!(all.len(), 17);
+    assert_eq!(all.first(), Some(&CursorShape::CURSOR_ARROW));
+    assert_eq!(all.last(), Some(&CursorShape::CURSOR_HELP));
+
+    for (i, shape) in all.iter().enumerate() {
+        assert_eq!(shape.ord(), i as i32);
+    }
+
+    // *_MAX is not an enumerator of its own.
+    assert!(!VariantOperator::all().contains(&VariantOperator::MAX));
+}
+
+#[itest]
+fn enum_from_str() {
+    // Rust and Godot names are both accepted; Key shortens `KEY_ESCAPE` to `ESCAPE`.
+    assert_eq!("ESCAPE".parse::<Key>().ok(), Some(Key::ESCAPE));
+    assert_eq!("KEY_ESCAPE".parse::<Key>().ok(), Some(Key::ESCAPE));
+
+    // Enumerators whose Rust name is not shortened are accepted under that single name.
+    assert_eq!(
+        "CURSOR_HELP".parse::<CursorShape>().ok(),
+        Some(CursorShape::CURSOR_HELP)
+    );
+
+    let err = "NOT_A_KEY".parse::<Key>().unwrap_err();
+    assert!(err.to_string().contains("NOT_A_KEY"), "{err}");
+    assert!("escape".parse::<Key>().is_err());
+}
+
+#[itest]
+fn enum_display() {
+    // Display uses Godot names, Debug uses Rust names.
+    assert_eq!(format!("{}", Key::ESCAPE), "KEY_ESCAPE");
+    assert_eq!(format!("{:?}", Key::ESCAPE), "ESCAPE");
+
+    assert_eq!(format!("{}", VariantOperator::EQUAL), "OP_EQUAL");
+}
+
 #[itest]
 fn enum_equality() {
     // TODO: find 2 overlapping ords in same enum
@@ -70,3 +114,48 @@ fn add_surface_from_arrays() {
     let mut mesh = ArrayMesh::new();
     mesh.add_surface_from_arrays(PrimitiveType::PRIMITIVE_TRIANGLES, varray![]);
 }
+
+#[itest]
+fn bitfield_contains_insert_remove() {
+    use godot::obj::EngineBitfield;
+
+    let mut flags = ConnectFlags::DEFERRED | ConnectFlags::ONE_SHOT;
+    assert!(flags.contains(ConnectFlags::DEFERRED));
+    assert!(flags.contains(ConnectFlags::DEFERRED | ConnectFlags::ONE_SHOT));
+    assert!(!flags.contains(ConnectFlags::PERSIST));
+    assert!(!flags.contains(ConnectFlags::DEFERRED | ConnectFlags::PERSIST));
+
+    // is_set() checks for any common flag, contains() for all.
+    assert!(flags.is_set(ConnectFlags::DEFERRED | ConnectFlags::PERSIST));
+
+    flags.insert(ConnectFlags::PERSIST);
+    assert!(flags.contains(ConnectFlags::PERSIST));
+
+    flags.remove(ConnectFlags::DEFERRED | ConnectFlags::REFERENCE_COUNTED);
+    assert_eq!(flags, ConnectFlags::ONE_SHOT | ConnectFlags::PERSIST);
+
+    flags.toggle(ConnectFlags::ONE_SHOT | ConnectFlags::DEFERRED);
+    assert_eq!(flags, ConnectFlags::PERSIST | ConnectFlags::DEFERRED);
+    assert_eq!(flags.ord(), 3);
+}
+
+#[itest]
+fn bitfield_iter() {
+    use godot::obj::EngineBitfield;
+
+    let flags = ConnectFlags::REFERENCE_COUNTED | ConnectFlags::DEFERRED | ConnectFlags::ONE_SHOT;
+    let set: Vec<ConnectFlags> = flags.iter().collect();
+    assert_eq!(
+        set,
+        vec![
+            ConnectFlags::DEFERRED,
+            ConnectFlags::ONE_SHOT,
+            ConnectFlags::REFERENCE_COUNTED
+        ]
+    );
+
+    assert_eq!(ConnectFlags::from_ord(0).iter().count(), 0);
+
+    let all_bits = ConnectFlags::from_ord(u64::MAX);
+    assert_eq!(all_bits.iter().count(), 64);
+}
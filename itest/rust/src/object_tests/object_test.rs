@@ -15,7 +15,7 @@ use godot::classes::{
 };
 use godot::global::instance_from_id;
 use godot::meta::{FromGodot, GodotType, ToGodot};
-use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
+use godot::obj::{Base, BorrowError, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
 use godot::register::{godot_api, GodotClass};
 use godot::sys::{self, interface_fn, GodotFfi};
 
@@ -43,6 +43,25 @@ fn object_construct_value() {
     assert_eq!(obj.bind().value, 222);
 }
 
+#[itest]
+fn object_construct_try_init() {
+    FALLIBLE_INIT_VALID.set(true);
+    let obj = FallibleInit::new_alloc();
+    assert_eq!(obj.bind().value, 333);
+    obj.free();
+
+    // Construction from Godot yields null, construction from Rust panics.
+    FALLIBLE_INIT_VALID.set(false);
+    let variant = godot::classes::ClassDb::singleton().instantiate("FallibleInit".into());
+    assert!(variant.is_nil());
+
+    expect_panic("new_alloc() with failing try_init()", || {
+        let _ = FallibleInit::new_alloc();
+    });
+
+    FALLIBLE_INIT_VALID.set(true);
+}
+
 #[itest]
 fn object_user_roundtrip_return() {
     let value: i16 = 17943;
@@ -217,6 +236,22 @@ fn object_from_instance_id_unrelated_type() {
     node.free();
 }
 
+#[itest]
+fn object_from_instance_id_panics() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    expect_panic("from_instance_id() with bad type", || {
+        let _ = Gd::<RefCounted>::from_instance_id(id);
+    });
+
+    node.free();
+
+    expect_panic("from_instance_id() with dead ID", || {
+        let _ = Gd::<Node3D>::from_instance_id(id);
+    });
+}
+
 #[itest]
 fn object_new_has_instance_id() {
     let obj = ObjPayload::new_alloc();
@@ -265,6 +300,41 @@ fn object_user_free_during_bind() {
     obj.free(); // now succeeds
 }
 
+#[itest]
+fn object_user_try_bind_conflicts() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    // Shared borrows can coexist, but block exclusive ones.
+    {
+        let guard = obj.bind();
+        assert_eq!(copy.try_bind().map(|r| r.value), Ok(111));
+
+        let err = copy
+            .try_bind_mut()
+            .expect_err("try_bind_mut() while shared-bound");
+        assert_eq!(err, BorrowError::AlreadyBoundShared);
+        drop(guard);
+    }
+
+    // Exclusive borrow blocks both.
+    {
+        let mut guard = obj.bind_mut();
+        guard.value = 222;
+
+        let err = copy.try_bind().expect_err("try_bind() while mut-bound");
+        assert_eq!(err, BorrowError::AlreadyBoundMut);
+
+        let err = copy
+            .try_bind_mut()
+            .expect_err("try_bind_mut() while mut-bound");
+        assert_eq!(err, BorrowError::AlreadyBoundMut);
+    }
+
+    // Once guards are dropped, borrowing succeeds again.
+    assert_eq!(copy.try_bind_mut().map(|r| r.value), Ok(222));
+}
+
 #[itest]
 fn object_engine_freed_argument_passing(ctx: &TestContext) {
     let node: Gd<Node> = Node::new_alloc();
@@ -554,6 +624,28 @@ fn object_engine_upcast() {
     object.free();
 }
 
+#[itest]
+fn object_engine_eq_instance() {
+    let node3d: Gd<Node3D> = Node3D::new_alloc();
+    let node = node3d.clone().upcast::<Node>();
+    let object = node3d.clone().upcast::<Object>();
+
+    assert!(node3d.eq_instance(&node));
+    assert!(node.eq_instance(&node3d));
+    assert!(object.eq_instance(&node));
+    assert!(node3d.eq_instance(&node3d));
+
+    let other: Gd<Node> = Node::new_alloc();
+    assert!(!node3d.eq_instance(&other));
+    assert!(!other.eq_instance(&object));
+
+    other.free();
+    node3d.free();
+
+    // Identity remains well-defined after destruction.
+    assert!(node.eq_instance(&object));
+}
+
 fn ref_instance_id(obj: &Object) -> InstanceId {
     let obj_ptr = obj.__object_ptr();
     // SAFETY: raw FFI call since we can't access get_instance_id() of a raw Object anymore, and call() needs &mut.
@@ -648,6 +740,35 @@ fn object_engine_bad_downcast() {
     object2.free();
 }
 
+#[itest]
+fn object_engine_cast_or_free() {
+    let node3d: Gd<Node3D> = Node3D::new_alloc();
+    let id = node3d.instance_id();
+
+    // Success: ownership moves, object stays alive.
+    let node: Gd<Node> = node3d.upcast();
+    let node3d = node.cast_or_free::<Node3D>().expect("cast_or_free");
+    assert_eq!(node3d.instance_id(), id);
+    node3d.free();
+
+    // Failure: object is destroyed.
+    let object: Gd<Object> = Object::new_alloc();
+    let id = object.instance_id();
+
+    assert_eq!(object.cast_or_free::<Node3D>(), None);
+    assert!(Gd::<Object>::try_from_instance_id(id).is_err());
+}
+
+#[itest]
+fn object_engine_cast_or_free_refcounted() {
+    let object = RefCounted::new_gd().upcast::<Object>();
+    let id = object.instance_id();
+
+    // Dynamically ref-counted objects are not freed, only released.
+    assert_eq!(object.cast_or_free::<Node3D>(), None);
+    assert!(Gd::<Object>::try_from_instance_id(id).is_err());
+}
+
 #[itest]
 fn object_engine_accept_polymorphic() {
     let mut node = Camera3D::new_alloc();
@@ -1065,3 +1186,26 @@ fn double_use_reference() {
 #[derive(GodotClass)]
 #[class(no_init, base = EditorPlugin, editor_plugin, tool)]
 struct CustomEditorPlugin;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+thread_local! {
+    static FALLIBLE_INIT_VALID: Cell<bool> = const { Cell::new(true) };
+}
+
+#[derive(GodotClass)]
+#[class(try_init, base = Object)]
+struct FallibleInit {
+    value: i32,
+    base: Base<Object>,
+}
+
+impl FallibleInit {
+    fn try_init(base: Base<Object>) -> Result<Self, String> {
+        if !FALLIBLE_INIT_VALID.get() {
+            return Err("validation failed".to_string());
+        }
+
+        Ok(Self { value: 333, base })
+    }
+}
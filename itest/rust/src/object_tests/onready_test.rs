@@ -7,10 +7,10 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::classes::notify::NodeNotification;
-use godot::classes::INode;
+use godot::classes::{INode, Node};
 use godot::register::{godot_api, GodotClass};
 
-use godot::obj::{Gd, OnReady};
+use godot::obj::{Base, Gd, NewAlloc, OnReady};
 use godot::prelude::ToGodot;
 
 #[itest]
@@ -41,6 +41,31 @@ fn onready_deref_on_uninit() {
     });
 }
 
+#[itest]
+fn onready_try_get_on_uninit() {
+    let mut auto = OnReady::<i32>::new(|| 42);
+    assert_eq!(auto.try_get(), None);
+    assert_eq!(auto.try_get_mut(), None);
+
+    let mut manual = OnReady::<i32>::manual();
+    assert_eq!(manual.try_get(), None);
+    assert_eq!(manual.try_get_mut(), None);
+}
+
+#[itest]
+fn onready_try_get_on_init() {
+    let mut auto = OnReady::<i32>::new(|| 42);
+    godot::private::auto_init(&mut auto);
+    assert_eq!(auto.try_get(), Some(&42));
+
+    *auto.try_get_mut().unwrap() = 43;
+    assert_eq!(*auto, 43);
+
+    let mut manual = OnReady::<i32>::manual();
+    manual.init(7);
+    assert_eq!(manual.try_get(), Some(&7));
+}
+
 #[itest]
 fn onready_multi_init() {
     expect_panic("init() on already initialized container fails", || {
@@ -142,6 +167,28 @@ fn onready_property_access() {
     obj.free();
 }
 
+#[itest]
+fn onready_node_lookup() {
+    let mut obj = OnReadyWithNodes::new_alloc();
+
+    let mut child = Node::new_alloc();
+    child.set_name("Child".into());
+    obj.add_child(child.clone());
+
+    obj.notify(NodeNotification::READY);
+
+    {
+        let obj = obj.bind();
+        assert_eq!(*obj.child, child);
+        assert_eq!(*obj.present_child, Some(child));
+
+        // Missing node does not panic, but yields None.
+        assert_eq!(*obj.missing_child, None);
+    }
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -234,3 +281,21 @@ impl INode for OnReadyWithoutImpl {
     // Declare another function to ensure virtual getter must be provided.
     fn process(&mut self, _delta: f64) {}
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Class that looks up child nodes through #[init(node)] and #[init(try_node)].
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnReadyWithNodes {
+    #[init(node = "Child")]
+    child: OnReady<Gd<Node>>,
+
+    #[init(try_node = "Child")]
+    present_child: OnReady<Option<Gd<Node>>>,
+
+    #[init(try_node = "Missing")]
+    missing_child: OnReady<Option<Gd<Node>>>,
+
+    base: Base<Node>,
+}
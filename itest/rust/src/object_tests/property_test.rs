@@ -5,13 +5,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
+use godot::builtin::{
+    dict, Array, Color, Dictionary, GString, PackedByteArray, PackedInt32Array, StringName,
+    Variant, VariantType, Vector2,
+};
+use godot::classes::{ClassDb, INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
-use godot::meta::{GodotConvert, ToGodot};
-use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
+use godot::meta::{GodotConvert, PropertyInfo, ToGodot};
+use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, GodotClass, NewAlloc, NewGd};
+use godot::register::property::export_info_functions::{export_range, ExportRange};
 use godot::register::property::{Export, PropertyHintInfo, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+use godot::sys::GdextBuild;
 use godot::test::itest;
 
 // No tests currently, tests using these classes are in Godot scripts.
@@ -303,6 +308,213 @@ struct CheckAllExports {
     color_no_alpha: Color,
 }
 
+#[itest]
+fn export_file_dir_hints() {
+    let properties = ClassDb::singleton()
+        .class_get_property_list(CheckAllExports::class_name().to_string_name());
+
+    let check_hint = |name: &str, hint: PropertyHint, hint_string: &str| {
+        let property = find_property(&properties, name);
+
+        check_property(&property, "type", VariantType::STRING.ord());
+        check_property(&property, "hint", hint.ord());
+        check_property(&property, "hint_string", hint_string);
+    };
+
+    check_hint("file_no_filter", PropertyHint::FILE, "");
+    check_hint("file_filter", PropertyHint::FILE, "*.jpg");
+    check_hint("global_file_no_filter", PropertyHint::GLOBAL_FILE, "");
+    check_hint("global_file_filter", PropertyHint::GLOBAL_FILE, "*.txt");
+    check_hint("dir", PropertyHint::DIR, "");
+    check_hint("global_dir", PropertyHint::GLOBAL_DIR, "");
+}
+
+#[itest]
+fn export_text_hints() {
+    let properties = ClassDb::singleton()
+        .class_get_property_list(CheckAllExports::class_name().to_string_name());
+
+    let normal = find_property(&properties, "normal");
+    check_property(&normal, "type", VariantType::STRING.ord());
+    check_property(&normal, "hint", PropertyHint::NONE.ord());
+
+    let multiline = find_property(&properties, "multiline");
+    check_property(&multiline, "type", VariantType::STRING.ord());
+    check_property(&multiline, "hint", PropertyHint::MULTILINE_TEXT.ord());
+    check_property(&multiline, "hint_string", "");
+
+    let placeholder = find_property(&properties, "placeholder");
+    check_property(&placeholder, "type", VariantType::STRING.ord());
+    check_property(&placeholder, "hint", PropertyHint::PLACEHOLDER_TEXT.ord());
+    check_property(&placeholder, "hint_string", "placeholder");
+}
+
+#[itest]
+fn property_info_new_range() {
+    let property = PropertyInfo::new_range::<f64>("my_range", 0.0, 10.0, 0.1);
+
+    assert_eq!(property.property_name, StringName::from("my_range"));
+    assert_eq!(property.variant_type, VariantType::FLOAT);
+    assert_eq!(property.hint, PropertyHint::RANGE);
+    assert_eq!(property.hint_string, GString::from("0,10,0.1"));
+}
+
+#[itest]
+fn property_info_export_range_builder() {
+    let check_hint = |range: ExportRange, expected: &str| {
+        let PropertyHintInfo { hint, hint_string } = range.into_hint_info();
+        assert_eq!(hint, PropertyHint::RANGE);
+        assert_eq!(hint_string, GString::from(expected));
+    };
+
+    check_hint(ExportRange::new(0.0, 10.0), "0,10");
+    check_hint(ExportRange::new(-1.5, 1.5).step(0.25), "-1.5,1.5,0.25");
+    check_hint(
+        ExportRange::new(0.0, 100.0).step(1.0).or_greater().exp(),
+        "0,100,1,or_greater,exp",
+    );
+    check_hint(
+        ExportRange::new(0.0, 360.0)
+            .radians()
+            .hide_slider()
+            .suffix("rad"),
+        "0,360,radians,hide_slider,suffix:rad",
+    );
+    check_hint(ExportRange::new(0.0, 5.0).suffix("m"), "0,5,suffix:m");
+
+    // Builder and the long-form function produce the same hint.
+    assert_eq!(
+        ExportRange::new(0.0, 10.0)
+            .step(0.1)
+            .or_less()
+            .degrees()
+            .into_hint_info()
+            .hint_string,
+        export_range(0.0, 10.0, Some(0.1), false, true, false, false, true, false).hint_string,
+    );
+}
+
+#[itest]
+fn var_option_round_trip() {
+    let mut value: Option<Gd<RefCounted>> = None;
+    assert_eq!(value.get_property(), None);
+
+    let obj = RefCounted::new_gd();
+    value.set_property(Some(obj.clone()));
+    assert_eq!(value.get_property(), Some(obj.clone()));
+    assert_eq!(value, Some(obj));
+
+    value.set_property(None);
+    assert_eq!(value.get_property(), None);
+    assert_eq!(value, None);
+}
+
+#[itest]
+fn export_option_hint() {
+    // Option<T> uses the same hint as T; None is represented as null in the inspector.
+    assert_eq!(
+        <Option<Gd<Resource>> as Export>::default_export_info(),
+        <Gd<Resource> as Export>::default_export_info(),
+    );
+    assert_eq!(
+        <Option<Gd<Node>> as Var>::property_hint(),
+        <Gd<Node> as Var>::property_hint(),
+    );
+}
+
+#[itest]
+fn var_option_builtin_round_trip() {
+    // Builtins without a nullable representation go through Variant, with nil standing for None.
+    let mut value: Option<GString> = None;
+    assert_eq!(value.get_property(), Variant::nil());
+
+    value.set_property("hello".to_variant());
+    assert_eq!(value.get_property(), "hello".to_variant());
+    assert_eq!(value, Some(GString::from("hello")));
+
+    value.set_property(Variant::nil());
+    assert_eq!(value, None);
+
+    assert_eq!(
+        <Option<GString> as Export>::default_export_info(),
+        <GString as Export>::default_export_info(),
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct OptionalBuiltinProperties {
+    #[export]
+    nickname: Option<GString>,
+
+    #[var]
+    offset: Option<Vector2>,
+}
+
+#[itest]
+fn export_option_builtin() {
+    let mut obj = OptionalBuiltinProperties::new_gd();
+    assert_eq!(obj.get("nickname".into()), Variant::nil());
+
+    obj.set("nickname".into(), "Kitty".to_variant());
+    obj.set("offset".into(), Vector2::new(1.0, 2.0).to_variant());
+    assert_eq!(obj.bind().nickname, Some(GString::from("Kitty")));
+    assert_eq!(obj.bind().offset, Some(Vector2::new(1.0, 2.0)));
+
+    obj.set("nickname".into(), Variant::nil());
+    assert_eq!(obj.bind().nickname, None);
+
+    let property = find_property(&obj.get_property_list(), "nickname");
+
+    // Registered as Variant, so that the property accepts null.
+    check_property(&property, "type", VariantType::NIL.ord());
+}
+
+#[itest]
+fn export_array_int_element_hints() {
+    let int_ty = VariantType::INT.ord();
+    let range = PropertyHint::RANGE.ord();
+
+    let check_hint = |hint_info: PropertyHintInfo, expected: &str| {
+        assert_eq!(hint_info.hint, PropertyHint::TYPE_STRING);
+        assert_eq!(hint_info.hint_string, GString::from(expected));
+    };
+
+    // Sized integers carry their value range.
+    check_hint(
+        <Array<i8> as Export>::default_export_info(),
+        &format!("{int_ty}/{range}:-128,127"),
+    );
+    check_hint(
+        <Array<u16> as Export>::default_export_info(),
+        &format!("{int_ty}/{range}:0,65535"),
+    );
+    check_hint(
+        <Array<i32> as Export>::default_export_info(),
+        &format!("{int_ty}/{range}:-2147483648,2147483647"),
+    );
+
+    // i64 covers Godot's full int range, so no range is needed.
+    let expected_i64 = if GdextBuild::since_api("4.3") {
+        format!("{int_ty}:")
+    } else {
+        format!("{int_ty}:int")
+    };
+    check_hint(<Array<i64> as Export>::default_export_info(), &expected_i64);
+
+    // Packed arrays have fixed-width elements and use the plain element type, like GDScript.
+    if GdextBuild::since_api("4.3") {
+        check_hint(
+            <PackedByteArray as Export>::default_export_info(),
+            &expected_i64,
+        );
+        check_hint(
+            <PackedInt32Array as Export>::default_export_info(),
+            &expected_i64,
+        );
+    }
+}
+
 #[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
 #[godot(via = i64)]
 #[repr(i64)]
@@ -363,6 +575,24 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
+#[godot(via = i64)]
+pub enum TestEnumExplicitOrds {
+    A = 10,
+    B = 20,
+    C = 30,
+}
+
+#[itest]
+fn derive_export_enum_explicit_ords() {
+    let hint_info = <TestEnumExplicitOrds as Export>::default_export_info();
+    assert_eq!(hint_info.hint, PropertyHint::ENUM);
+    assert_eq!(hint_info.hint_string, GString::from("A:10,B:20,C:30"));
+
+    // Export and Var agree on the hint.
+    assert_eq!(hint_info, <TestEnumExplicitOrds as Var>::property_hint());
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -444,6 +674,49 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ClampedProperty {
+    #[export]
+    #[var(get = get_health, set = set_health)]
+    health: i32,
+}
+
+#[godot_api]
+impl ClampedProperty {
+    #[func]
+    fn get_health(&self) -> i32 {
+        self.health
+    }
+
+    #[func]
+    fn set_health(&mut self, health: i32) {
+        self.health = health.clamp(0, 100);
+    }
+}
+
+#[itest]
+fn export_custom_setter_clamps() {
+    let mut obj = ClampedProperty::new_gd();
+
+    // Property access from Godot goes through the custom accessors.
+    obj.set("health".into(), 150.to_variant());
+    assert_eq!(obj.get("health".into()), 100.to_variant());
+
+    obj.set("health".into(), (-5).to_variant());
+    assert_eq!(obj.bind().health, 0);
+
+    obj.set("health".into(), 42.to_variant());
+    assert_eq!(obj.bind().get_health(), 42);
+}
+
+fn find_property(properties: &Array<Dictionary>, name: &str) -> Dictionary {
+    properties
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == name.to_variant())
+        .unwrap_or_else(|| panic!("property `{name}` not found"))
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -37,6 +37,13 @@ impl ReentrantClass {
         self.first_called_post = true;
     }
 
+    #[func]
+    fn first_signal_self(&mut self) {
+        self.first_called_pre = true;
+        self.emit_signal_self("some_signal".into(), &[]);
+        self.first_called_post = true;
+    }
+
     #[func]
     fn second(&mut self) {
         self.second_called = true;
@@ -79,3 +86,26 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn reentrant_emit_self_bind_mut_succeeds() {
+    let mut class = ReentrantClass::new_alloc();
+    let id = class.instance_id();
+
+    // Handler re-borrows the emitting object mutably, while the emitter's own bind_mut() guard is still alive.
+    let callable = Callable::from_fn("handler", move |_args: &[&Variant]| {
+        let mut emitter = Gd::<ReentrantClass>::from_instance_id(id);
+        emitter.bind_mut().second_called = true;
+        Ok(Variant::nil())
+    });
+    class.connect("some_signal".into(), callable);
+
+    class.bind_mut().first_signal_self();
+
+    assert!(class.bind().first_called_pre);
+    assert!(class.bind().first_called_post);
+    assert!(class.bind().second_called);
+
+    class.free()
+}
@@ -8,7 +8,7 @@
 use std::fmt::Debug;
 
 use godot::builtin::{GString, Vector2};
-use godot::meta::ToGodot;
+use godot::meta::{FromGodot, ToGodot};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -36,6 +36,16 @@ enum EnumStringy {
     D = 50,
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = GString)]
+enum EnumStringyRenamed {
+    Idle,
+    #[godot(rename = "run-fast")]
+    Running,
+    #[godot(rename = jumping)]
+    Jumping,
+}
+
 #[derive(GodotConvert, Clone, PartialEq, Debug)]
 #[godot(via = i64)]
 enum EnumInty {
@@ -71,6 +81,27 @@ fn enum_stringy() {
     assert_eq!(EnumStringy::D.to_godot(), "D".into());
 }
 
+#[itest]
+fn enum_stringy_renamed() {
+    roundtrip(EnumStringyRenamed::Idle);
+    roundtrip(EnumStringyRenamed::Running);
+    roundtrip(EnumStringyRenamed::Jumping);
+
+    assert_eq!(EnumStringyRenamed::Idle.to_godot(), "Idle".into());
+    assert_eq!(EnumStringyRenamed::Running.to_godot(), "run-fast".into());
+    assert_eq!(EnumStringyRenamed::Jumping.to_godot(), "jumping".into());
+
+    assert_eq!(
+        EnumStringyRenamed::try_from_godot("run-fast".into()).ok(),
+        Some(EnumStringyRenamed::Running)
+    );
+
+    // Original Rust names are no longer accepted for renamed variants.
+    assert!(EnumStringyRenamed::try_from_godot("Running".into()).is_err());
+    assert!(EnumStringyRenamed::try_from_godot("unknown".into()).is_err());
+    assert!(EnumStringyRenamed::try_from_godot("".into()).is_err());
+}
+
 #[itest]
 fn enum_inty() {
     roundtrip(EnumInty::A);
@@ -86,6 +117,50 @@ fn enum_inty() {
     assert_eq!(EnumInty::E.to_godot(), 2);
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = i64)]
+enum EnumExplicitOrds {
+    Low = 10,
+    High = 20,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = i64)]
+enum EnumImplicitOrds {
+    First,
+    Second,
+}
+
+#[itest]
+fn enum_inty_explicit_discriminants() {
+    roundtrip(EnumExplicitOrds::Low);
+    roundtrip(EnumExplicitOrds::High);
+
+    assert_eq!(EnumExplicitOrds::Low.to_godot(), 10);
+    assert_eq!(EnumExplicitOrds::High.to_godot(), 20);
+
+    assert_eq!(
+        EnumExplicitOrds::try_from_godot(20).ok(),
+        Some(EnumExplicitOrds::High)
+    );
+    assert!(EnumExplicitOrds::try_from_godot(0).is_err());
+    assert!(EnumExplicitOrds::try_from_godot(11).is_err());
+    assert!(EnumExplicitOrds::try_from_variant(&15.to_variant()).is_err());
+}
+
+#[itest]
+fn enum_inty_implicit_discriminants_match_rust() {
+    assert_eq!(
+        EnumImplicitOrds::First.to_godot(),
+        EnumImplicitOrds::First as i64
+    );
+    assert_eq!(
+        EnumImplicitOrds::Second.to_godot(),
+        EnumImplicitOrds::Second as i64
+    );
+    assert_eq!(EnumImplicitOrds::First.to_godot(), 0);
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
@@ -33,6 +33,16 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func(rename = "doThing")]
+    fn do_thing(&self) -> GString {
+        "done".into()
+    }
+
+    #[func(defaults = (count = 3, delay = 1.5))]
+    fn describe_spawn(&self, kind: GString, count: i64, delay: f64) -> GString {
+        format!("{kind}:{count}:{delay}").into()
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -296,6 +306,32 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_rename_string_literal() {
+    assert!(class_has_method::<FuncObj>("doThing"));
+    assert!(!class_has_method::<FuncObj>("do_thing"));
+
+    let mut object = FuncObj::new_gd();
+    let result = object.call("doThing".into(), &[]);
+    assert_eq!(result, "done".to_variant());
+}
+
+#[itest]
+fn func_default_args() {
+    let mut object = FuncObj::new_gd();
+    let mut call = |args: &[Variant]| object.call("describe_spawn".into(), args).to::<GString>();
+
+    assert_eq!(call(&["orc".to_variant()]), "orc:3:1.5".into());
+    assert_eq!(
+        call(&["orc".to_variant(), 7.to_variant()]),
+        "orc:7:1.5".into()
+    );
+    assert_eq!(
+        call(&["orc".to_variant(), 7.to_variant(), 0.25.to_variant()]),
+        "orc:7:0.25".into()
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 